@@ -0,0 +1,36 @@
+// Runge's phenomenon: interpolating 1/(1 + 25x^2) on [-1, 1] at equispaced
+// nodes diverges as the degree grows, while nodes that cluster at the ends
+// of the interval converge. Run with `cargo run --example runge`.
+
+use ch2::nodes::NodeFamily;
+use ch2::{LagrangePolynomial, Point, PolyGetPoints, PolyInterpolate};
+
+fn runge(x: f64) -> f64 {
+    1.0 / (1.0 + 25.0 * x * x)
+}
+
+fn main() {
+    let grid: Vec<f64> = (0..=2000).map(|i| -1.0 + i as f64 / 1000.0).collect();
+
+    print!("{:>6}", "degree");
+    for family in NodeFamily::ALL {
+        print!(" {:>11}", family);
+    }
+    println!();
+
+    for degree in (4..=40).step_by(4) {
+        print!("{:>6}", degree);
+        for family in NodeFamily::ALL {
+            let points: Vec<Point<f64>> = family.nodes(degree + 1, -1.0, 1.0)
+                .into_iter()
+                .map(|x| Point::new(x, runge(x)))
+                .collect();
+            let lp = LagrangePolynomial::interpolate(&points);
+            let max_err = grid.iter()
+                .map(|x| (lp.get_y(x) - runge(*x)).abs())
+                .fold(0.0, f64::max);
+            print!(" {:>11.3e}", max_err);
+        }
+        println!();
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{Complex, Polynomial, Real, Scalar};
+
+// Simultaneous approximation of all complex roots.
+//
+// Starting from n points spread on a circle that encloses all roots, every
+// approximation z_i is corrected at once, taking the others into account:
+//   Durand-Kerner: z_i -= p(z_i) / (a_n prod_{j != i} (z_i - z_j))
+//   Aberth-Ehrlich: z_i -= N_i / (1 - N_i sum_{j != i} 1 / (z_i - z_j))
+// with the Newton correction N_i = p(z_i) / p'(z_i). Durand-Kerner converges
+// quadratically and Aberth-Ehrlich cubically to simple roots, both only
+// linearly to multiple ones. Corrections are applied in place as soon as they
+// are computed (Gauss-Seidel style), and an approximation is frozen once p(z_i)
+// is below the rounding error of evaluating p there.
+// See Bini, "Numerical computation of polynomial zeros by means of Aberth's
+// method", Numer. Algorithms 13 (1996).
+
+/// The iteration used by [`RootFinder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Iteration {
+    Aberth,
+    DurandKerner,
+}
+
+/// Settings for finding all complex roots of a polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RootFinder {
+    pub iteration : Iteration,
+    /// Upper bound on the number of sweeps over all approximations.
+    pub max_iterations : usize,
+    /// The number of Newton steps applied to each root at the end. A step is
+    /// only kept if it decreases `|p|`.
+    pub polish_steps : usize
+}
+
+impl Default for RootFinder {
+    fn default() -> Self {
+        RootFinder { iteration: Iteration::Aberth, max_iterations: 500, polish_steps: 2 }
+    }
+}
+
+/// An approximation of a complex root together with a bound on its error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexRoot<T = f32> {
+    pub value : Complex<T>,
+    /// The radius of a disk around `value` that contains a root. For the
+    /// roots of a polynomial of degree n these are the inclusion disks
+    /// `n |p(z_i)| / |a_n prod_{j != i} (z_i - z_j)|`, widened by the rounding
+    /// error of `p(z_i)`: every connected component of the union of the
+    /// disks that is made up of m disks contains exactly m roots.
+    pub error_bound : T,
+    /// Whether the iteration reached the rounding level of `p` at this root
+    /// within [`RootFinder::max_iterations`].
+    pub converged : bool
+}
+
+// p(z), p'(z) and a bound on the rounding error of p(z)
+fn eval<T: Real>(coeffs: &[Complex<T>], abs_coeffs: &[T], z: Complex<T>) -> (Complex<T>, Complex<T>, T) {
+    let (mut p, mut dp) = (Complex::zero(), Complex::zero());
+    for c in coeffs.iter().rev() {
+        dp = dp * z + p;
+        p = p * z + *c;
+    }
+    let r = z.abs();
+    let rho = abs_coeffs.iter().rev().fold(T::zero(), |acc, c| acc * r + *c);
+    let n = T::from_i64(2 * coeffs.len() as i64);
+    (p, dp, n * T::epsilon() * rho)
+}
+
+impl RootFinder {
+    /// All complex roots of `p`, repeated according to multiplicity and
+    /// sorted by real and then imaginary part. The zero polynomial and
+    /// constants have none.
+    ///
+    /// A root of multiplicity m can only be determined to about the m-th
+    /// root of the working precision, e.g. about `1e-5` for a triple root in
+    /// `f64`; its approximations then form a cluster, and their error bounds
+    /// grow accordingly.
+    pub fn roots<T: Real>(&self, p: &Polynomial<T>) -> Vec<ComplexRoot<T>> {
+        let Some(n) = p.degree() else {
+            return Vec::new();
+        };
+        // zero roots are exact and split off
+        let zeros = p.coeffs().iter().take_while(|c| c.is_zero()).count();
+        let mut roots = vec![ComplexRoot { value: Complex::zero(), error_bound: T::zero(), converged: true }; zeros];
+        let q = Polynomial::new(&p.coeffs()[zeros..]);
+        let m = n - zeros;
+        if m == 0 {
+            return roots;
+        }
+        let coeffs: Vec<Complex<T>> = q.coeffs().iter().map(|&c| Complex::from(c)).collect();
+        let abs_coeffs: Vec<T> = q.coeffs().iter().map(|c| c.abs()).collect();
+        let lead = coeffs[m];
+
+        // start on a circle of the radius of Cauchy's bound, rotated away
+        // from the real axis so that conjugate pairs can separate
+        let radius = q.cauchy_bound();
+        let mut z: Vec<Complex<T>> = (0..m)
+            .map(|k| Complex::from_polar(radius, std::f64::consts::TAU * k as f64 / m as f64 + 0.4))
+            .collect();
+        let mut converged = vec![false; m];
+        for _ in 0..self.max_iterations {
+            if converged.iter().all(|c| *c) {
+                break;
+            }
+            for i in 0..m {
+                if converged[i] {
+                    continue;
+                }
+                let (pz, dpz, noise) = eval(&coeffs, &abs_coeffs, z[i]);
+                if pz.abs() <= noise {
+                    converged[i] = true;
+                    continue;
+                }
+                let others = (0..m).filter(|&j| j != i);
+                let correction = match self.iteration {
+                    Iteration::Aberth => {
+                        let newton = pz / dpz;
+                        let s = others.fold(Complex::zero(), |acc, j| acc + Complex::one() / (z[i] - z[j]));
+                        newton / (Complex::one() - newton * s)
+                    }
+                    Iteration::DurandKerner => {
+                        pz / others.fold(lead, |acc, j| acc * (z[i] - z[j]))
+                    }
+                };
+                if !correction.is_finite() {
+                    // p'(z_i) = 0 or coinciding approximations; nudge apart
+                    z[i] = z[i] + Complex::from_polar(T::epsilon().sqrt() * (T::one() + z[i].abs()), i as f64);
+                    continue;
+                }
+                z[i] = z[i] - correction;
+                if correction.abs() <= T::epsilon() * z[i].abs() {
+                    converged[i] = true;
+                }
+            }
+        }
+
+        for zi in z.iter_mut() {
+            for _ in 0..self.polish_steps {
+                let (pz, dpz, _) = eval(&coeffs, &abs_coeffs, *zi);
+                let next = *zi - pz / dpz;
+                if !next.is_finite() || eval(&coeffs, &abs_coeffs, next).0.abs() >= pz.abs() {
+                    break;
+                }
+                *zi = next;
+            }
+        }
+
+        let nm = T::from_i64(m as i64);
+        roots.extend((0..m).map(|i| {
+            let (pz, _, noise) = eval(&coeffs, &abs_coeffs, z[i]);
+            let denom = (0..m).filter(|&j| j != i).fold(lead, |acc, j| acc * (z[i] - z[j])).abs();
+            let error_bound = nm * (pz.abs() + noise) / denom;
+            let error_bound = if error_bound.is_finite() { error_bound } else { T::from_f64(f64::INFINITY) };
+            ComplexRoot { value: z[i], error_bound, converged: converged[i] }
+        }));
+        roots.sort_by(|a, b| {
+            (a.value.re, a.value.im).partial_cmp(&(b.value.re, b.value.im)).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        roots
+    }
+}
+
+impl<T: Real> Polynomial<T> {
+    /// All complex roots, found with the default [`RootFinder`].
+    pub fn complex_roots(&self) -> Vec<ComplexRoot<T>> {
+        RootFinder::default().roots(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wilkinson(n: i64) -> Polynomial<f64> {
+        (1..=n).fold(Polynomial::one(), |acc, k| acc * Polynomial::new([-(k as f64), 1.0]))
+    }
+
+    #[test]
+    fn complex_conjugate_roots() {
+        // (x + 1)(x^2 + 2x + 5)
+        let p = Polynomial::new([5.0, 7.0, 3.0, 1.0]);
+        let roots = p.complex_roots();
+        let expected = [Complex::new(-1.0, -2.0), Complex::new(-1.0, 0.0), Complex::new(-1.0, 2.0)];
+        assert_eq!(roots.len(), 3);
+        for (root, z) in roots.iter().zip(expected) {
+            assert!(root.converged);
+            assert!((root.value - z).abs() < 1e-12, "{} != {}", root.value, z);
+            assert!((root.value - z).abs() <= root.error_bound);
+        }
+    }
+
+    #[test]
+    fn both_iterations_find_the_wilkinson_roots() {
+        let p = wilkinson(10);
+        for iteration in [Iteration::Aberth, Iteration::DurandKerner] {
+            let roots = RootFinder { iteration, ..RootFinder::default() }.roots(&p);
+            assert_eq!(roots.len(), 10);
+            for (k, root) in roots.iter().enumerate() {
+                let z = Complex::from((k + 1) as f64);
+                assert!((root.value - z).abs() < 1e-6, "{:?}: {} != {}", iteration, root.value, z);
+                assert!((root.value - z).abs() <= root.error_bound, "{:?}: bound of {}", iteration, z);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_roots_are_exact() {
+        let roots = Polynomial::new([0.0, 0.0, -4.0, 1.0]).complex_roots();
+        assert_eq!(roots.iter().filter(|r| r.value == Complex::zero() && r.error_bound == 0.0).count(), 2);
+        assert!((roots[2].value - Complex::from(4.0)).abs() < 1e-12);
+        assert!(Polynomial::new([3.0]).complex_roots().is_empty());
+    }
+
+    // the documented accuracy for a root of multiplicity m, about the m-th
+    // root of the working precision
+    fn assert_cluster(roots: &[ComplexRoot<f64>], z: Complex<f64>, m: i32) {
+        let accuracy = 10.0 * f64::EPSILON.powf(1.0 / m as f64);
+        let cluster: Vec<&ComplexRoot<f64>> = roots.iter().filter(|r| (r.value - z).abs() < accuracy).collect();
+        assert_eq!(cluster.len(), m as usize, "{} in {:?}", z, roots);
+        for root in cluster {
+            assert!(root.converged, "{:?}", root);
+            assert!((root.value - z).abs() <= root.error_bound, "{:?} misses {}", root, z);
+        }
+    }
+
+    #[test]
+    fn multiple_roots_form_clusters() {
+        // (x - 1)^3
+        let roots = Polynomial::new([-1.0, 3.0, -3.0, 1.0]).complex_roots();
+        assert_eq!(roots.len(), 3);
+        assert_cluster(&roots, Complex::from(1.0), 3);
+        assert!(roots.iter().all(|r| (r.value - Complex::from(1.0)).abs() < 1e-5));
+        // (x^2 + 1)^2
+        let roots = Polynomial::new([1.0, 0.0, 2.0, 0.0, 1.0]).complex_roots();
+        assert_eq!(roots.len(), 4);
+        assert_cluster(&roots, Complex::new(0.0, -1.0), 2);
+        assert_cluster(&roots, Complex::new(0.0, 1.0), 2);
+    }
+}
@@ -0,0 +1,504 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+// Arbitrary precision integers in sign-magnitude form. The magnitude is a
+// little-endian vector of 32-bit limbs so that products of two limbs fit a u64.
+// Only what exact rational arithmetic needs is implemented, with the textbook
+// algorithms from Knuth, TAOCP Vol. 2, Section 4.3.1.
+
+/// An arbitrary precision signed integer.
+///
+/// The magnitude never has trailing (most significant) zero limbs and zero is
+/// never negative, so derived equality is numeric equality.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative : bool,
+    mag : Vec<u32>
+}
+
+/// Error returned when parsing a [`BigInt`] (or a [`Rational`](crate::Rational))
+/// from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNumberError;
+
+impl fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid number literal")
+    }
+}
+
+impl Error for ParseNumberError {}
+
+fn trim(mut mag: Vec<u32>) -> Vec<u32> {
+    while mag.last() == Some(&0) {
+        mag.pop();
+    }
+    mag
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0_u64;
+    for (i, l) in long.iter().enumerate() {
+        let t = *l as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        out.push(t as u32);
+        carry = t >> 32;
+    }
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+// requires a >= b
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0_i64;
+    for (i, l) in a.iter().enumerate() {
+        let t = *l as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        out.push(t as u32);
+        borrow = if t < 0 { 1 } else { 0 };
+    }
+    trim(out)
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![0_u32; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0_u64;
+        for (j, y) in b.iter().enumerate() {
+            let t = *x as u64 * *y as u64 + out[i + j] as u64 + carry;
+            out[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        out[i + b.len()] = carry as u32;
+    }
+    trim(out)
+}
+
+fn divrem_small(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+    let mut q = vec![0_u32; a.len()];
+    let mut r = 0_u64;
+    for i in (0..a.len()).rev() {
+        let t = (r << 32) | a[i] as u64;
+        q[i] = (t / d as u64) as u32;
+        r = t % d as u64;
+    }
+    (trim(q), r as u32)
+}
+
+// Knuth's Algorithm D, following the presentation in Hacker's Delight 9-2.
+// requires v nonzero
+fn divrem_mag(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_mag(u, v) == Ordering::Less {
+        return (Vec::new(), u.to_vec());
+    }
+    if v.len() == 1 {
+        let (q, r) = divrem_small(u, v[0]);
+        return (q, trim(vec![r]));
+    }
+
+    // normalize so that the top limb of the divisor has its high bit set
+    let s = v[v.len() - 1].leading_zeros();
+    let shl = |x: &[u32], extra: bool| -> Vec<u32> {
+        let mut out: Vec<u32> = (0..x.len())
+            .map(|i| {
+                let lo = if i > 0 && s > 0 { x[i - 1] >> (32 - s) } else { 0 };
+                (x[i] << s) | lo
+            })
+            .collect();
+        if extra {
+            out.push(if s > 0 { x[x.len() - 1] >> (32 - s) } else { 0 });
+        }
+        out
+    };
+    let vn = shl(v, false);
+    let mut un = shl(u, true);
+
+    let n = v.len();
+    let m = u.len() - n;
+    let b = 1_u64 << 32;
+    let mut q = vec![0_u32; m + 1];
+
+    for j in (0..=m).rev() {
+        let num = ((un[j + n] as u64) << 32) | un[j + n - 1] as u64;
+        let mut qhat = num / vn[n - 1] as u64;
+        let mut rhat = num % vn[n - 1] as u64;
+        while qhat >= b || qhat * vn[n - 2] as u64 > ((rhat << 32) | un[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += vn[n - 1] as u64;
+            if rhat >= b {
+                break;
+            }
+        }
+
+        // multiply and subtract
+        let mut k = 0_i64;
+        for i in 0..n {
+            let p = qhat * vn[i] as u64;
+            let t = un[i + j] as i64 - k - (p & 0xffff_ffff) as i64;
+            un[i + j] = t as u32;
+            k = (p >> 32) as i64 - (t >> 32);
+        }
+        let t = un[j + n] as i64 - k;
+        un[j + n] = t as u32;
+
+        q[j] = qhat as u32;
+        // the estimate was one too large, add the divisor back
+        if t < 0 {
+            q[j] = q[j].wrapping_sub(1);
+            let mut carry = 0_u64;
+            for i in 0..n {
+                let t = un[i + j] as u64 + vn[i] as u64 + carry;
+                un[i + j] = t as u32;
+                carry = t >> 32;
+            }
+            un[j + n] = un[j + n].wrapping_add(carry as u32);
+        }
+    }
+
+    // unnormalize the remainder
+    let r = (0..n)
+        .map(|i| {
+            let hi = if s > 0 { un[i + 1] << (32 - s) } else { 0 };
+            (un[i] >> s) | hi
+        })
+        .collect();
+    (trim(q), trim(r))
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mag: Vec<u32>) -> Self {
+        let mag = trim(mag);
+        BigInt { negative: negative && !mag.is_empty(), mag }
+    }
+
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    pub fn one() -> Self {
+        BigInt::from(1_u64)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_one(&self) -> bool {
+        !self.negative && self.mag == [1]
+    }
+
+    /// -1, 0 or 1 according to the sign of `self`.
+    pub fn signum(&self) -> i32 {
+        if self.is_zero() { 0 } else if self.negative { -1 } else { 1 }
+    }
+
+    pub fn abs(&self) -> Self {
+        BigInt { negative: false, mag: self.mag.clone() }
+    }
+
+    /// Quotient and remainder of truncating division, so that
+    /// `self == q * rhs + r` and `r` has the sign of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &BigInt) -> (BigInt, BigInt) {
+        assert!(!rhs.is_zero(), "division by zero");
+        let (q, r) = divrem_mag(&self.mag, &rhs.mag);
+        (BigInt::from_parts(self.negative != rhs.negative, q),
+         BigInt::from_parts(self.negative, r))
+    }
+
+    /// The non-negative greatest common divisor, with `gcd(0, 0) == 0`.
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let (mut a, mut b) = (self.mag.clone(), other.mag.clone());
+        while !b.is_empty() {
+            let (_, r) = divrem_mag(&a, &b);
+            a = b;
+            b = r;
+        }
+        BigInt::from_parts(false, a)
+    }
+
+    /// The number of significant bits of the magnitude, zero for zero.
+    pub fn bits(&self) -> u64 {
+        match self.mag.last() {
+            Some(top) => (self.mag.len() as u64 - 1) * 32 + (32 - top.leading_zeros()) as u64,
+            None => 0,
+        }
+    }
+
+    /// The nearest `f64`, or an infinity if the magnitude is too large.
+    pub fn to_f64(&self) -> f64 {
+        let m = self.mag.iter().rev().fold(0.0, |acc, l| acc * 4294967296.0 + *l as f64);
+        if self.negative { -m } else { m }
+    }
+
+    /// `self * 2^k`.
+    pub fn shl(&self, k: usize) -> BigInt {
+        let (limbs, bits) = (k / 32, k % 32);
+        let mut mag = vec![0_u32; limbs];
+        let mut carry = 0_u32;
+        for l in &self.mag {
+            mag.push((l << bits) | carry);
+            carry = if bits > 0 { l >> (32 - bits) } else { 0 };
+        }
+        mag.push(carry);
+        BigInt::from_parts(self.negative, mag)
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(n: u64) -> Self {
+        BigInt::from_parts(false, vec![n as u32, (n >> 32) as u32])
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        let m = BigInt::from(n.unsigned_abs());
+        if n < 0 { -m } else { m }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.mag, &other.mag),
+            (true, true) => cmp_mag(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+        // peel off base 10^9 digits, least significant first
+        let mut chunks = Vec::new();
+        let mut mag = self.mag.clone();
+        while !mag.is_empty() {
+            let (q, r) = divrem_small(&mag, 1_000_000_000);
+            chunks.push(r);
+            mag = q;
+        }
+        let mut s = chunks.pop().unwrap().to_string();
+        for c in chunks.iter().rev() {
+            s.push_str(&format!("{:09}", c));
+        }
+        f.pad_integral(!self.negative, "", &s)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseNumberError);
+        }
+        let ten = BigInt::from(10_u64);
+        let m = digits.bytes()
+            .fold(BigInt::zero(), |acc, b| &(&acc * &ten) + &BigInt::from((b - b'0') as u64));
+        Ok(if negative { -m } else { m })
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.mag)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, add_mag(&self.mag, &rhs.mag));
+        }
+        match cmp_mag(&self.mag, &rhs.mag) {
+            Ordering::Less => BigInt::from_parts(rhs.negative, sub_mag(&rhs.mag, &self.mag)),
+            _ => BigInt::from_parts(self.negative, sub_mag(&self.mag, &rhs.mag)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &(-rhs)
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(self.negative != rhs.negative, mul_mag(&self.mag, &rhs.mag))
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+forward_binop!(impl[] BigInt; Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::{Rng, Xoshiro256};
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    fn from_i128(n: i128) -> BigInt {
+        let m = n.unsigned_abs();
+        BigInt::from_parts(n < 0, (0..4).map(|i| (m >> (32 * i)) as u32).collect())
+    }
+
+    fn assert_div_rem(u: &BigInt, v: &BigInt) {
+        let (q, r) = u.div_rem(v);
+        assert_eq!(&(&q * v) + &r, *u, "{} / {}", u, v);
+        assert_eq!(cmp_mag(&r.mag, &v.mag), Ordering::Less, "{} % {}", u, v);
+        assert!(r.is_zero() || r.is_negative() == u.is_negative(), "{} % {}", u, v);
+    }
+
+    #[test]
+    fn division_matches_i128_in_all_sign_combinations() {
+        let mut rng = Xoshiro256::new(5);
+        for _ in 0..500 {
+            // magnitudes of one to four limbs, so that every divisor length occurs
+            let draw = |rng: &mut Xoshiro256| {
+                let n = (((rng.next_u64() as u128) << 64) | rng.next_u64() as u128) >> (32 * rng.below(4));
+                (n >> 1) as i128
+            };
+            let (a, b) = (draw(&mut rng), draw(&mut rng).max(1));
+            for (a, b) in [(a, b), (-a, b), (a, -b), (-a, -b)] {
+                let (q, r) = from_i128(a).div_rem(&from_i128(b));
+                assert_eq!((q.to_string(), r.to_string()), ((a / b).to_string(), (a % b).to_string()), "{} / {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn division_with_add_back() {
+        // the case from Hacker's Delight in which the estimated quotient digit
+        // survives both corrections and the divisor must be added back
+        let u = BigInt::from_parts(false, vec![0, 0, 0x8000_0000, 0x7fff_ffff]);
+        let v = BigInt::from_parts(false, vec![1, 0, 0x8000_0000]);
+        let (q, r) = u.div_rem(&v);
+        assert_eq!(q.mag, [0xffff_fffe]);
+        assert_eq!(r.mag, [2, 0xffff_ffff, 0x7fff_ffff]);
+        for (u, v) in [(&u, &v), (&-&u, &v), (&u, &-&v), (&-&u, &-&v)] {
+            assert_div_rem(u, v);
+        }
+        let u = big("-123456789012345678901234567890123456789012345678901234567890");
+        let v = big("98765432109876543210987654321");
+        for (u, v) in [(&u, &v), (&-&u, &v), (&u, &-&v), (&-&u, &-&v), (&v, &u)] {
+            assert_div_rem(u, v);
+        }
+        assert_eq!(big("12").gcd(&big("-18")), big("6"));
+        assert_eq!(BigInt::zero().gcd(&BigInt::zero()), BigInt::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn division_by_zero_panics() {
+        big("1").div_rem(&BigInt::zero());
+    }
+
+    #[test]
+    fn decimal_round_trips() {
+        for s in ["0", "7", "-7", "999999999", "1000000000", "1000000001", "-999999999999999999", "1000000000000000000",
+                  "18446744073709551616", "123000000000000000456", "-340282366920938463463374607431768211457"] {
+            assert_eq!(big(s).to_string(), s);
+        }
+        assert_eq!(big("+0042"), big("42"));
+        assert_eq!(big("-0"), BigInt::zero());
+        assert!(!big("-0").is_negative());
+        assert_eq!(format!("{:>6}", big("-12")), "   -12");
+        for bad in ["", "-", "+", "1.5", "1e3", " 1", "--1"] {
+            assert!(bad.parse::<BigInt>().is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn shifts_and_bits() {
+        assert_eq!(BigInt::one().shl(100).to_string(), "1267650600228229401496703205376");
+        assert_eq!(big("-3").shl(32), big("-12884901888"));
+        assert_eq!(big("5").shl(0), big("5"));
+        assert_eq!(BigInt::zero().shl(64), BigInt::zero());
+        assert_eq!(BigInt::one().shl(64).bits(), 65);
+        assert_eq!(BigInt::zero().bits(), 0);
+        assert_eq!(big("-1").shl(1023).to_f64(), -(2_f64.powi(1023)));
+    }
+
+    #[test]
+    fn ordering_and_signs() {
+        let mut xs = ["5", "-5", "0", "4294967296", "-4294967296", "-1"].map(big);
+        xs.sort();
+        assert_eq!(xs, ["-4294967296", "-5", "-1", "0", "5", "4294967296"].map(big));
+        assert_eq!(big("-5").signum(), -1);
+        assert_eq!(big("-5").abs(), big("5"));
+        assert_eq!(&big("4294967295") + &big("1"), big("4294967296"));
+        assert_eq!(&big("3") - &big("10"), big("-7"));
+        assert_eq!(&big("-4294967296") * &big("-4294967296"), big("18446744073709551616"));
+    }
+}
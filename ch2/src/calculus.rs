@@ -0,0 +1,77 @@
+use crate::{Polynomial, Scalar};
+
+// Formal differentiation and integration, term by term. Since only the field
+// operations are used, this works over any Scalar: d/dx x^i = i x^(i-1) and
+// the integral of x^i is x^(i+1) / (i + 1).
+
+impl<T: Scalar> Polynomial<T> {
+    /// The `k`-th derivative.
+    pub fn derivative(&self, k: usize) -> Polynomial<T> {
+        let coeffs = self.coeffs()
+            .iter()
+            .enumerate()
+            .skip(k)
+            .map(|(i, c)| {
+                // i (i - 1) ... (i - k + 1)
+                let falling = (i - k + 1..=i).fold(T::one(), |acc, j| acc * T::from_i64(j as i64));
+                c.clone() * falling
+            })
+            .collect::<Vec<T>>();
+        Polynomial::new(coeffs)
+    }
+
+    /// The antiderivative whose value at zero is `c`.
+    ///
+    /// # Panics
+    ///
+    /// Over a field of characteristic `p`, panics if the polynomial has a
+    /// nonzero term of degree `p - 1`, which has no antiderivative.
+    pub fn antiderivative(&self, c: T) -> Polynomial<T> {
+        let coeffs = std::iter::once(c)
+            .chain(self.coeffs()
+                .iter()
+                .enumerate()
+                .map(|(i, c)| if c.is_zero() { T::zero() } else { c.clone() / T::from_i64(i as i64 + 1) }))
+            .collect::<Vec<T>>();
+        Polynomial::new(coeffs)
+    }
+
+    /// The definite integral over `[a, b]`.
+    pub fn integrate(&self, a: &T, b: &T) -> T {
+        let antiderivative = self.antiderivative(T::zero());
+        antiderivative.horner(b) - antiderivative.horner(a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gf, Polynomial, Rational};
+
+    fn poly(coeffs: &[i64]) -> Polynomial<Rational> {
+        Polynomial::new(coeffs.iter().map(|&c| Rational::from(c)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn derivatives() {
+        let p = poly(&[1, 2, 3, 4]);
+        assert_eq!(p.derivative(0), p);
+        assert_eq!(p.derivative(1), poly(&[2, 6, 12]));
+        assert_eq!(p.derivative(3), poly(&[24]));
+        assert_eq!(p.derivative(4), Polynomial::zero());
+    }
+
+    #[test]
+    fn antiderivatives_and_integrals() {
+        let p = poly(&[2, 6, 12]);
+        assert_eq!(p.antiderivative(Rational::from(1)), poly(&[1, 2, 3, 4]));
+        assert_eq!(p.antiderivative(Rational::from(0)).derivative(1), p);
+        assert_eq!(p.integrate(&Rational::from(0), &Rational::from(2)), Rational::from(2 * 2 + 3 * 4 + 4 * 8));
+        assert_eq!(poly(&[1, 1]).integrate(&Rational::from(0), &Rational::from(1)), Rational::new(3, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn antiderivative_of_x_to_the_p_minus_one_panics() {
+        Polynomial::new([0, 0, 1].map(Gf::<3>::new)).antiderivative(Gf::new(0));
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::fft::{self, FFT_THRESHOLD};
+use crate::scalar::schoolbook_mul;
+use crate::{Real, Scalar};
+
+/// A complex number `re + im i` with floating point parts.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T = f32> {
+    pub re : T,
+    pub im : T
+}
+
+impl<T: Real> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+
+    /// The imaginary unit.
+    pub fn i() -> Self {
+        Complex { re: T::zero(), im: T::one() }
+    }
+
+    /// `r (cos theta + i sin theta)`. The trigonometric functions are
+    /// evaluated in `f64`.
+    pub fn from_polar(r: T, theta: f64) -> Self {
+        Complex { re: r * T::from_f64(theta.cos()), im: r * T::from_f64(theta.sin()) }
+    }
+
+    pub fn conj(self) -> Self {
+        Complex { re: self.re, im: -self.im }
+    }
+
+    /// The squared absolute value `re^2 + im^2`.
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// The absolute value, computed without overflow for large parts.
+    pub fn abs(self) -> T {
+        let (a, b) = (self.re.abs(), self.im.abs());
+        let (big, small) = if a > b { (a, b) } else { (b, a) };
+        if big.is_zero() {
+            return big;
+        }
+        let r = small / big;
+        big * (T::one() + r * r).sqrt()
+    }
+
+    pub fn scale(self, c: T) -> Self {
+        Complex { re: self.re * c, im: self.im * c }
+    }
+}
+
+impl<T: Real> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Complex { re, im: T::zero() }
+    }
+}
+
+impl<T: Real + fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, im) = if self.im < T::zero() { ('-', -self.im) } else { ('+', self.im) };
+        match f.precision() {
+            Some(p) => write!(f, "{:.*} {} {:.*}i", p, self.re, sign, p, im),
+            None => write!(f, "{} {} {}i", self.re, sign, im),
+        }
+    }
+}
+
+impl<T: Real> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Complex { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+impl<T: Real> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Complex { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+}
+
+impl<T: Real> Mul for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl<T: Real> Div for Complex<T> {
+    type Output = Self;
+
+    // Smith's algorithm, which avoids overflow in |rhs|^2.
+    // See Smith, "Algorithm 116: Complex division", CACM 5 (1962).
+    fn div(self, rhs: Self) -> Self {
+        if rhs.re.abs() >= rhs.im.abs() {
+            let r = rhs.im / rhs.re;
+            let d = rhs.re + rhs.im * r;
+            Complex { re: (self.re + self.im * r) / d, im: (self.im - self.re * r) / d }
+        } else {
+            let r = rhs.re / rhs.im;
+            let d = rhs.re * r + rhs.im;
+            Complex { re: (self.re * r + self.im) / d, im: (self.im * r - self.re) / d }
+        }
+    }
+}
+
+impl<T: Real> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Complex { re: -self.re, im: -self.im }
+    }
+}
+
+impl<T: Real> Scalar for Complex<T> {
+    fn zero() -> Self {
+        Complex { re: T::zero(), im: T::zero() }
+    }
+
+    fn one() -> Self {
+        Complex { re: T::one(), im: T::zero() }
+    }
+
+    fn from_i64(n: i64) -> Self {
+        Complex { re: T::from_i64(n), im: T::zero() }
+    }
+
+    fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    fn is_exact() -> bool {
+        false
+    }
+
+    fn mul_coeffs(a: &[Self], b: &[Self]) -> Vec<Self> {
+        if a.len().min(b.len()) < FFT_THRESHOLD {
+            schoolbook_mul(a, b)
+        } else {
+            fft::multiply_complex(a, b)
+        }
+    }
+}
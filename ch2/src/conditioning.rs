@@ -0,0 +1,262 @@
+use std::fmt;
+
+use crate::{Point, Scalar};
+
+// How much interpolation amplifies errors in the data.
+//
+// If the values y_j are perturbed by at most d, the interpolant changes by at
+// most L d on [a, b], where L is the Lebesgue constant of the nodes, the
+// maximum of the Lebesgue function sum_j |l_j(x)|. Computing the monomial
+// coefficients instead means solving a Vandermonde system, which amplifies
+// relative errors by up to its condition number.
+// See Trefethen, "Approximation Theory and Approximation Practice", Ch. 15.
+
+/// The Lebesgue function `sum_j |l_j(x)|` of the nodes `xs` at `x`, where
+/// `l_j` are the Lagrange basis polynomials. It is 1 at the nodes.
+pub fn lebesgue_function(xs: &[f64], x: f64) -> f64 {
+    lebesgue_at(xs, &Weights::new(xs), x)
+}
+
+// ln prod |x - x_k|, multiplying directly and only taking logarithms when the
+// product is about to over- or underflow
+fn log_abs_product(x: f64, xs: impl Iterator<Item = f64>) -> f64 {
+    let (mut log, mut product) = (0.0, 1.0);
+    for xk in xs {
+        product *= (x - xk).abs();
+        if !(1e-100..=1e100).contains(&product) {
+            log += product.ln();
+            product = 1.0;
+        }
+    }
+    log + product.ln()
+}
+
+// The absolute barycentric weights |lambda_j| = 1 / prod_{k != j} |x_j - x_k|.
+// They over- or underflow for a few hundred nodes, so they are stored as
+// |lambda_j| = scaled[j] * e^log_scale with the largest scaled weight 1.
+struct Weights {
+    scaled : Vec<f64>,
+    log_scale : f64
+}
+
+impl Weights {
+    fn new(xs: &[f64]) -> Self {
+        let logs: Vec<f64> = xs.iter()
+            .enumerate()
+            .map(|(j, xj)| -log_abs_product(*xj, xs.iter().enumerate().filter(|(k, _)| *k != j).map(|(_, xk)| *xk)))
+            .collect();
+        let log_scale = logs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Weights { scaled: logs.iter().map(|log| (log - log_scale).exp()).collect(), log_scale }
+    }
+}
+
+// sum_j |l_j(x)| = |prod_k (x - x_k)| sum_j |lambda_j| / |x - x_j|. Unlike the
+// barycentric quotient, whose denominator cancels catastrophically once the
+// Lebesgue function exceeds 1 / epsilon, every term here is positive.
+fn lebesgue_at(xs: &[f64], weights: &Weights, x: f64) -> f64 {
+    if xs.contains(&x) {
+        return 1.0;
+    }
+    let sum: f64 = xs.iter()
+        .zip(&weights.scaled)
+        .map(|(xj, w)| w / (x - xj).abs())
+        .sum();
+    (log_abs_product(x, xs.iter().copied()) + weights.log_scale + sum.ln()).exp()
+}
+
+/// The Lebesgue constant of the nodes `xs` on `[a, b]`, estimated as the
+/// maximum of the Lebesgue function over `samples` equally spaced points.
+///
+/// It is infinite if it exceeds the range of `f64` or could not be
+/// evaluated, e.g. for repeated nodes.
+pub fn lebesgue_constant(xs: &[f64], a: f64, b: f64, samples: usize) -> f64 {
+    let samples = samples.max(2);
+    let weights = Weights::new(xs);
+    (0..samples)
+        .map(|i| a + (b - a) * i as f64 / (samples - 1) as f64)
+        .map(|x| lebesgue_at(xs, &weights, x))
+        .fold(0.0, |max, l| if l.is_nan() { f64::INFINITY } else { max.max(l) })
+}
+
+// The maximum of the Lebesgue function over `per_gap` equally spaced points
+// in each gap between consecutive nodes, which holds one local maximum. This
+// follows clustered nodes better than equally spaced samples, with only
+// O(n) samples in all.
+fn lebesgue_constant_between_nodes(xs: &[f64], per_gap: usize) -> f64 {
+    let weights = Weights::new(xs);
+    let mut sorted = xs.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted.windows(2)
+        .flat_map(|w| (1..=per_gap).map(move |i| w[0] + (w[1] - w[0]) * i as f64 / (per_gap + 1) as f64))
+        .map(|x| lebesgue_at(xs, &weights, x))
+        .fold(1.0, |max, l| if l.is_nan() { f64::INFINITY } else { max.max(l) })
+}
+
+/// The condition number in the infinity norm of the Vandermonde matrix
+/// `V[i][j] = xs[i]^j`, or infinity if it is numerically singular.
+///
+/// The inverse is formed explicitly by Gauss-Jordan elimination with partial
+/// pivoting, which is accurate enough for an order of magnitude as long as
+/// the condition number is below `1 / epsilon`. The last row of `V^-1` holds
+/// the barycentric weights, so `||V|| sum_j |lambda_j|` is a lower bound; once
+/// that bound exceeds `1 / epsilon`, the inverse would be meaningless and
+/// the bound is returned instead, without the O(n^3) elimination.
+pub fn vandermonde_condition(xs: &[f64]) -> f64 {
+    let n = xs.len();
+    let v: Vec<Vec<f64>> = xs.iter()
+        .map(|x| (0..n).map(|j| f64::powi(*x, j as i32)).collect())
+        .collect();
+    if v.iter().flatten().any(|e| !e.is_finite()) {
+        return f64::INFINITY;
+    }
+    let norm = |m: &[Vec<f64>]| m.iter()
+        .map(|row| row.iter().map(|a| a.abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+
+    let weights = Weights::new(xs);
+    let bound = norm(&v) * (weights.log_scale + weights.scaled.iter().sum::<f64>().ln()).exp();
+    if bound.is_nan() {
+        return f64::INFINITY;
+    }
+    if bound >= 1.0 / f64::EPSILON {
+        return bound;
+    }
+
+    // reduce [V | I] to [I | V^-1]
+    let mut a: Vec<Vec<f64>> = v.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut r = row.clone();
+            r.extend((0..n).map(|j| if i == j { 1.0 } else { 0.0 }));
+            r
+        })
+        .collect();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs())).unwrap();
+        if a[pivot][col] == 0.0 || !a[pivot][col].is_finite() {
+            return f64::INFINITY;
+        }
+        a.swap(col, pivot);
+        let p = a[col][col];
+        a[col].iter_mut().for_each(|e| *e /= p);
+        let pivot_row = a[col].clone();
+        for (_, r) in a.iter_mut().enumerate().filter(|(row, _)| *row != col) {
+            let factor = r[col];
+            r.iter_mut().zip(&pivot_row).for_each(|(e, p)| *e -= factor * p);
+        }
+    }
+    let inverse: Vec<Vec<f64>> = a.into_iter().map(|row| row[n..].to_vec()).collect();
+    let condition = norm(&v) * norm(&inverse);
+    if condition.is_finite() && inverse.iter().flatten().all(|e| e.is_finite()) {
+        condition
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// A summary of how well-conditioned interpolation through a node set is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditioningReport {
+    /// The interval spanned by the nodes, on which the Lebesgue constant is
+    /// estimated.
+    pub interval : (f64, f64),
+    pub lebesgue_constant : f64,
+    pub vandermonde_condition : f64,
+    /// The largest acceptable amplification of data errors.
+    pub threshold : f64
+}
+
+impl ConditioningReport {
+    /// Analyzes the nodes of `points`, warning about amplification of data
+    /// errors beyond `threshold`.
+    ///
+    /// Returns `None` for an empty slice and for scalars without an
+    /// [`approx_f64`](Scalar::approx_f64), such as finite fields, where
+    /// conditioning has no meaning.
+    pub fn analyze<T: Scalar>(points: &[Point<T>], threshold: f64) -> Option<Self> {
+        let xs = points.iter()
+            .map(|p| p.x.approx_f64())
+            .collect::<Option<Vec<f64>>>()?;
+        let a = xs.iter().copied().reduce(f64::min)?;
+        let b = xs.iter().copied().reduce(f64::max)?;
+        Some(ConditioningReport {
+            interval: (a, b),
+            lebesgue_constant: lebesgue_constant_between_nodes(&xs, 16),
+            vandermonde_condition: vandermonde_condition(&xs),
+            threshold,
+        })
+    }
+
+    /// Human readable warnings for every amplification above the threshold.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.lebesgue_constant > self.threshold {
+            warnings.push(format!(
+                "errors in the data may be amplified up to {:.3e} times in the interpolant's values",
+                self.lebesgue_constant));
+        }
+        if self.vandermonde_condition > self.threshold {
+            warnings.push(format!(
+                "errors in the data may be amplified up to {:.3e} times in the monomial coefficients",
+                self.vandermonde_condition));
+        }
+        warnings
+    }
+
+    pub fn is_well_conditioned(&self) -> bool {
+        self.warnings().is_empty()
+    }
+}
+
+impl fmt::Display for ConditioningReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Lebesgue constant on [{}, {}]: {:.3e}, Vandermonde condition number: {:.3e}",
+            self.interval.0, self.interval.1, self.lebesgue_constant, self.vandermonde_condition)?;
+        for w in self.warnings() {
+            write!(f, "\nwarning: {}", w)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::NodeFamily;
+
+    fn report(family: NodeFamily, n: usize, a: f64, b: f64) -> ConditioningReport {
+        let points: Vec<Point<f64>> = family.nodes(n, a, b).into_iter().map(|x| Point::new(x, 0.0)).collect();
+        ConditioningReport::analyze(&points, 1e3).unwrap()
+    }
+
+    #[test]
+    fn lebesgue_function_of_few_nodes() {
+        assert_eq!(lebesgue_function(&[0.0, 1.0], 0.5), 1.0);
+        assert!((lebesgue_function(&[0.0, 1.0, 2.0], 0.5) - 1.25).abs() < 1e-14);
+        assert_eq!(lebesgue_function(&[0.0, 1.0, 2.0], 2.0), 1.0);
+    }
+
+    #[test]
+    fn chebyshev_lebesgue_constant_grows_logarithmically() {
+        // the Lebesgue constant grows like 2 / pi ln n
+        let n = 1200;
+        let r = report(NodeFamily::ChebyshevSecond, n, -1.0, 1.0);
+        let growth = 2.0 / std::f64::consts::PI * (n as f64).ln();
+        assert!(r.lebesgue_constant > growth && r.lebesgue_constant < growth + 1.0, "{}", r);
+    }
+
+    #[test]
+    fn huge_lebesgue_constants_are_not_lost() {
+        // about 2^n / (e n ln n) for n equispaced nodes
+        let r = report(NodeFamily::Equispaced, 200, 10.0, 100.0);
+        assert!(r.lebesgue_constant > 1e56 && r.lebesgue_constant < 1e58, "{}", r);
+        assert_eq!(r.vandermonde_condition, f64::INFINITY);
+        assert!(!r.is_well_conditioned());
+    }
+
+    #[test]
+    fn repeated_nodes_are_infinitely_ill_conditioned() {
+        assert_eq!(lebesgue_constant(&[0.0, 0.0, 1.0], 0.0, 1.0, 10), f64::INFINITY);
+        assert_eq!(vandermonde_condition(&[1.0, 1.0]), f64::INFINITY);
+    }
+}
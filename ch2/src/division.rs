@@ -0,0 +1,270 @@
+use crate::{Polynomial, Real, Scalar};
+
+// Polynomial long division and the Euclidean algorithm.
+// See https://en.wikipedia.org/wiki/Polynomial_greatest_common_divisor
+
+// long division of coefficient vectors, returns (quotient, remainder) untrimmed
+fn long_division<T: Scalar>(a: &[T], d: &[T]) -> (Vec<T>, Vec<T>) {
+    let m = d.len() - 1;
+    if a.len() <= m {
+        return (Vec::new(), a.to_vec());
+    }
+    let lc = d[m].clone();
+    let mut r = a.to_vec();
+    let mut q = vec![T::zero(); a.len() - m];
+    for k in (0..q.len()).rev() {
+        q[k] = r[k + m].clone() / lc.clone();
+        // the leading term cancels by construction, so it is never computed
+        for j in 0..m {
+            r[k + j] = r[k + j].clone() - q[k].clone() * d[j].clone();
+        }
+    }
+    r.truncate(m);
+    (q, r)
+}
+
+impl<T: Scalar> Polynomial<T> {
+    /// Quotient and remainder of polynomial long division, so that
+    /// `self == q * divisor + r` with `r` of lower degree than `divisor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>) {
+        assert!(!divisor.is_zero(), "division by the zero polynomial");
+        let (q, r) = long_division(self.coeffs(), divisor.coeffs());
+        (Polynomial::new(q), Polynomial::new(r))
+    }
+
+    /// Whether `divisor` divides `self` without remainder. Every polynomial
+    /// is divisible by a nonzero constant and only zero is divisible by zero.
+    pub fn is_divisible_by(&self, divisor: &Polynomial<T>) -> bool {
+        self.div_exact(divisor).is_some()
+    }
+
+    /// The quotient `self / divisor` if the division is exact.
+    pub fn div_exact(&self, divisor: &Polynomial<T>) -> Option<Polynomial<T>> {
+        if divisor.is_zero() {
+            return self.is_zero().then(Polynomial::zero);
+        }
+        let (q, r) = self.div_rem(divisor);
+        r.is_zero().then_some(q)
+    }
+
+    /// The polynomial scaled to have leading coefficient one; zero stays zero.
+    pub fn monic(&self) -> Polynomial<T> {
+        match self.leading_coeff() {
+            Some(lc) => self.scale(&(T::one() / lc.clone())),
+            None => Polynomial::zero(),
+        }
+    }
+
+    /// The monic greatest common divisor, or zero if both are zero.
+    pub fn gcd(&self, other: &Polynomial<T>) -> Polynomial<T> {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    /// The extended Euclidean algorithm: returns `(g, s, t)` with `g` the
+    /// monic gcd and `s * self + t * other == g`.
+    ///
+    /// If both operands are nonzero and not constant multiples of each other,
+    /// the Bézout coefficients are the minimal ones, i.e.
+    /// `deg s < deg other - deg g` and `deg t < deg self - deg g`, where the
+    /// zero polynomial counts as having lower degree than any other. For
+    /// `other == c * self` the result is `(self / lc, 0, 1 / (c * lc))` with
+    /// `lc` the leading coefficient of `self`. If `other` is zero, it is
+    /// `(self / lc, 1 / lc, 0)`, and symmetrically if `self` is zero; if both
+    /// are zero, it is `(0, 1, 0)`.
+    pub fn xgcd(&self, other: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>, Polynomial<T>) {
+        // invariant: r_i = s_i * self + t_i * other
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Polynomial::one(), Polynomial::zero());
+        let (mut t0, mut t1) = (Polynomial::zero(), Polynomial::one());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            let s = &s0 - &(&q * &s1);
+            let t = &t0 - &(&q * &t1);
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1, s);
+            (t0, t1) = (t1, t);
+        }
+        match r0.leading_coeff() {
+            Some(lc) => {
+                let inv = T::one() / lc.clone();
+                (r0.scale(&inv), s0.scale(&inv), t0.scale(&inv))
+            }
+            None => (r0, s0, t0),
+        }
+    }
+}
+
+// Over floating point numbers the remainder of an exact division is rarely
+// exactly zero, so these variants treat small coefficients as zero.
+
+impl<T: Real> Polynomial<T> {
+    /// Drops trailing coefficients whose magnitude is at most `tol` times the
+    /// largest coefficient.
+    pub fn trim_tol(&self, tol: T) -> Polynomial<T> {
+        let cutoff = tol * self.max_abs_coeff();
+        let mut coeffs = self.coeffs().to_vec();
+        while coeffs.last().is_some_and(|c| c.abs() <= cutoff) {
+            coeffs.pop();
+        }
+        Polynomial::new(coeffs)
+    }
+
+    /// Like [`div_rem`](Polynomial::div_rem), but remainder coefficients of
+    /// magnitude at most `tol` times the largest coefficient of `self` are
+    /// rounded to zero.
+    pub fn div_rem_tol(&self, divisor: &Polynomial<T>, tol: T) -> (Polynomial<T>, Polynomial<T>) {
+        assert!(!divisor.is_zero(), "division by the zero polynomial");
+        let (q, r) = long_division(self.coeffs(), divisor.coeffs());
+        let cutoff = tol * self.max_abs_coeff();
+        let r: Vec<T> = r.into_iter()
+            .map(|c| if c.abs() <= cutoff { T::zero() } else { c })
+            .collect();
+        (Polynomial::new(q), Polynomial::new(r))
+    }
+
+    /// Like [`div_exact`](Polynomial::div_exact) with the remainder tested
+    /// by [`div_rem_tol`](Polynomial::div_rem_tol).
+    pub fn div_exact_tol(&self, divisor: &Polynomial<T>, tol: T) -> Option<Polynomial<T>> {
+        if divisor.is_zero() {
+            return self.is_zero().then(Polynomial::zero);
+        }
+        let (q, r) = self.div_rem_tol(divisor, tol);
+        r.is_zero().then_some(q)
+    }
+
+    /// The monic gcd, computed with [`div_rem_tol`](Polynomial::div_rem_tol)
+    /// so that approximate common factors are found.
+    ///
+    /// The Euclidean algorithm is numerically unstable, so `tol` has to be
+    /// well above the rounding error of the inputs, e.g. `1e-8` for data
+    /// accurate to `f64` precision.
+    pub fn gcd_tol(&self, other: &Polynomial<T>, tol: T) -> Polynomial<T> {
+        let (mut a, mut b) = (self.clone(), other.trim_tol(tol));
+        while !b.is_zero() {
+            let r = a.div_rem_tol(&b, tol).1;
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    fn max_abs_coeff(&self) -> T {
+        self.coeffs().iter()
+            .fold(T::zero(), |acc, c| if c.abs() > acc { c.abs() } else { acc })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::q;
+    use crate::Rational;
+
+    fn poly(coeffs: &[&str]) -> Polynomial<Rational> {
+        Polynomial::new(coeffs.iter().map(|c| q(c)).collect::<Vec<_>>())
+    }
+
+    fn assert_division(a: &Polynomial<Rational>, d: &Polynomial<Rational>) {
+        let (quot, r) = a.div_rem(d);
+        assert_eq!(&(&quot * d) + &r, *a);
+        assert!(r.degree() < d.degree(), "remainder {:?} of {:?} / {:?}", r, a, d);
+    }
+
+    #[test]
+    fn division_with_remainder() {
+        let a = poly(&["1", "-2", "0", "3/2", "5"]);
+        for d in [poly(&["1", "1"]), poly(&["-7/3", "0", "2"]), poly(&["4"]), a.clone(), poly(&["0", "0", "0", "0", "0", "1"])] {
+            assert_division(&a, &d);
+        }
+        assert_division(&Polynomial::zero(), &poly(&["1", "1"]));
+        // (x^2 - 1) / (x - 1) = x + 1
+        assert_eq!(poly(&["-1", "0", "1"]).div_rem(&poly(&["-1", "1"])), (poly(&["1", "1"]), Polynomial::zero()));
+    }
+
+    #[test]
+    fn exact_division() {
+        let (a, b) = (poly(&["1", "1"]), poly(&["-2", "0", "3"]));
+        let ab = &a * &b;
+        assert_eq!(ab.div_exact(&a), Some(b.clone()));
+        assert!(ab.is_divisible_by(&b));
+        assert!(!ab.is_divisible_by(&poly(&["1", "-1"])));
+        assert!(ab.is_divisible_by(&poly(&["3"])));
+        assert_eq!(ab.div_exact(&Polynomial::zero()), None);
+        assert_eq!(Polynomial::<Rational>::zero().div_exact(&Polynomial::zero()), Some(Polynomial::zero()));
+        assert!(Polynomial::<Rational>::zero().is_divisible_by(&a));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by the zero polynomial")]
+    fn division_by_zero_panics() {
+        poly(&["1", "1"]).div_rem(&Polynomial::zero());
+    }
+
+    #[test]
+    fn monic_and_gcd() {
+        assert_eq!(poly(&["1", "2", "4"]).monic(), poly(&["1/4", "1/2", "1"]));
+        assert_eq!(Polynomial::<Rational>::zero().monic(), Polynomial::zero());
+        // (x - 1)(x + 2) and (x - 1)(2x - 3) share x - 1
+        let a = &poly(&["-1", "1"]) * &poly(&["2", "1"]);
+        let b = &poly(&["-1", "1"]) * &poly(&["-3", "2"]);
+        assert_eq!(a.gcd(&b), poly(&["-1", "1"]));
+        assert_eq!(a.gcd(&poly(&["5", "0", "1"])), Polynomial::one());
+        assert_eq!(a.gcd(&Polynomial::zero()), a.monic());
+        assert_eq!(Polynomial::<Rational>::zero().gcd(&Polynomial::zero()), Polynomial::zero());
+    }
+
+    #[test]
+    fn bezout_identity() {
+        let a = &poly(&["-1", "1"]) * &poly(&["2", "0", "1"]);
+        let b = &poly(&["-1", "1"]) * &poly(&["3", "1"]);
+        let c = poly(&["0", "0", "0", "2"]);
+        let zero = Polynomial::zero();
+        for (x, y) in [(&a, &b), (&b, &a), (&a, &c), (&a, &a), (&a, &zero), (&zero, &b), (&zero, &zero)] {
+            let (g, s, t) = x.xgcd(y);
+            assert_eq!(&(&s * x) + &(&t * y), g);
+            assert_eq!(g, x.gcd(y));
+            if !x.is_zero() && !y.is_zero() && x != y {
+                let dg = g.degree().unwrap();
+                assert!(s.degree().is_none_or(|d| d + dg < y.degree().unwrap()));
+                assert!(t.degree().is_none_or(|d| d + dg < x.degree().unwrap()));
+            }
+        }
+        // proportional operands give s = 0 and a constant t
+        let (_, s, t) = a.xgcd(&a.scale(&q("3")));
+        assert_eq!((s, t), (zero.clone(), poly(&["1/3"])));
+        // a zero operand leaves the other one, scaled to be monic
+        let (g, s, t) = a.xgcd(&zero);
+        assert_eq!((g, s, t), (a.clone(), Polynomial::one(), zero.clone()));
+        let (g, s, t) = c.xgcd(&zero);
+        assert_eq!((g, s, t), (c.monic(), poly(&["1/2"]), zero.clone()));
+        assert_eq!(zero.xgcd(&zero), (zero.clone(), Polynomial::one(), zero.clone()));
+    }
+
+    #[test]
+    fn approximate_common_factors() {
+        let lin = |r: f64| Polynomial::new([-r, 1.0]);
+        let a = &lin(1.0) * &Polynomial::new([2.0, 0.0, 1.0]);
+        let b = &lin(1.0000000001) * &lin(-3.0);
+        // the roots differ by 1e-10, so strictly there is no common factor
+        assert_eq!(a.gcd(&b).degree(), Some(0));
+        let g = a.gcd_tol(&b, 1e-8);
+        assert_eq!(g.degree(), Some(1));
+        assert!(g.horner(&1.0).abs() < 1e-8, "{:?}", g);
+        let q = (&a * &lin(4.0)).div_exact_tol(&g, 1e-8).unwrap();
+        assert!((&q - &(&Polynomial::new([2.0, 0.0, 1.0]) * &lin(4.0))).coeffs().iter().all(|c| c.abs() < 1e-8));
+        assert_eq!(a.div_exact_tol(&lin(3.0), 1e-8), None);
+        assert_eq!(Polynomial::new([1.0, 2.0, 1e-20, -1e-19]).trim_tol(1e-12), Polynomial::new([1.0, 2.0]));
+        let (_, r) = a.div_rem_tol(&lin(1.0 + 1e-14), 1e-10);
+        assert!(r.is_zero());
+    }
+}
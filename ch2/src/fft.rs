@@ -0,0 +1,221 @@
+use std::f64::consts::PI;
+
+use crate::{Complex, Polynomial, Real, Scalar};
+
+// The discrete Fourier transform X_k = sum_j x_j e^{-2 pi i jk / n} is the
+// evaluation of the polynomial with coefficients x_j at the n-th roots of
+// unity, and its inverse is interpolation at them. The fast Fourier transform
+// computes it in O(n log n): radix-2 Cooley-Tukey for powers of two, and for
+// other lengths Bluestein's algorithm, which rewrites the DFT as a
+// convolution of power of two length using jk = (j^2 + k^2 - (k - j)^2) / 2.
+// See https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm and
+// https://en.wikipedia.org/wiki/Chirp_Z-transform#Bluestein's_algorithm
+
+/// Above this many coefficients in both factors, multiplication of
+/// polynomials over floating point and complex numbers switches from the
+/// schoolbook method to the FFT.
+pub const FFT_THRESHOLD: usize = 64;
+
+// the unscaled transform with kernel e^{sign 2 pi i jk / n}
+fn transform<T: Real>(data: &mut Vec<Complex<T>>, sign: f64) {
+    if data.len().is_power_of_two() {
+        radix2(data, sign);
+    } else if data.len() > 1 {
+        *data = bluestein(data, sign);
+    }
+}
+
+// in-place iterative Cooley-Tukey, for power of two lengths
+fn radix2<T: Real>(data: &mut [Complex<T>], sign: f64) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        // twiddles are computed directly rather than by repeated
+        // multiplication, which would accumulate rounding errors
+        let twiddles: Vec<Complex<T>> = (0..len / 2)
+            .map(|k| Complex::from_polar(T::one(), sign * 2.0 * PI * k as f64 / len as f64))
+            .collect();
+        for chunk in data.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((u, v), w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let t = *v * *w;
+                (*u, *v) = (*u + t, *u - t);
+            }
+        }
+        len <<= 1;
+    }
+}
+
+// e^{sign 2 pi i jk / n} = c_j c_k conj(c_{k - j}) with c_j = e^{sign pi i j^2 / n}
+fn bluestein<T: Real>(data: &[Complex<T>], sign: f64) -> Vec<Complex<T>> {
+    let n = data.len();
+    // j^2 is reduced modulo 2n, where the chirp is periodic, to keep the
+    // angle accurate
+    let chirp: Vec<Complex<T>> = (0..n)
+        .map(|j| Complex::from_polar(T::one(), sign * PI * ((j * j) % (2 * n)) as f64 / n as f64))
+        .collect();
+    let m = (2 * n - 1).next_power_of_two();
+    let mut a = vec![Complex::zero(); m];
+    for (a, (x, c)) in a.iter_mut().zip(data.iter().zip(&chirp)) {
+        *a = *x * *c;
+    }
+    let mut b = vec![Complex::zero(); m];
+    b[0] = chirp[0].conj();
+    for j in 1..n {
+        b[j] = chirp[j].conj();
+        b[m - j] = chirp[j].conj();
+    }
+    radix2(&mut a, -1.0);
+    radix2(&mut b, -1.0);
+    let mut conv: Vec<Complex<T>> = a.iter().zip(&b).map(|(a, b)| *a * *b).collect();
+    radix2(&mut conv, 1.0);
+    let scale = T::one() / T::from_i64(m as i64);
+    conv.iter().zip(&chirp).map(|(x, c)| (*x * *c).scale(scale)).collect()
+}
+
+/// The discrete Fourier transform `X_k = sum_j x_j e^{-2 pi i jk / n}` of
+/// any length.
+pub fn fft<T: Real>(data: &[Complex<T>]) -> Vec<Complex<T>> {
+    let mut out = data.to_vec();
+    transform(&mut out, -1.0);
+    out
+}
+
+/// The inverse of [`fft`], `x_j = 1/n sum_k X_k e^{2 pi i jk / n}`.
+pub fn ifft<T: Real>(data: &[Complex<T>]) -> Vec<Complex<T>> {
+    let mut out = data.to_vec();
+    transform(&mut out, 1.0);
+    let scale = T::one() / T::from_i64(out.len().max(1) as i64);
+    out.into_iter().map(|x| x.scale(scale)).collect()
+}
+
+/// The values of `p` at the `n`-th roots of unity `w^0, ..., w^{n-1}` with
+/// `w = e^{2 pi i / n}`, in O(n log n) plus the degree of `p`.
+pub fn eval_roots_of_unity<T: Real>(p: &Polynomial<Complex<T>>, n: usize) -> Vec<Complex<T>> {
+    if n == 0 {
+        return Vec::new();
+    }
+    // w^n = 1, so only p modulo x^n - 1 matters
+    let mut data = vec![Complex::zero(); n];
+    for (j, c) in p.coeffs().iter().enumerate() {
+        data[j % n] = data[j % n] + *c;
+    }
+    transform(&mut data, 1.0);
+    data
+}
+
+/// The polynomial of degree below `n` taking the given values at the `n`-th
+/// roots of unity, in the order of [`eval_roots_of_unity`].
+pub fn interpolate_roots_of_unity<T: Real>(values: &[Complex<T>]) -> Polynomial<Complex<T>> {
+    let mut data = values.to_vec();
+    transform(&mut data, -1.0);
+    let scale = T::one() / T::from_i64(values.len().max(1) as i64);
+    Polynomial::new(data.into_iter().map(|x| x.scale(scale)).collect::<Vec<_>>())
+}
+
+/// The product of the polynomials with coefficients `a` and `b`, by
+/// pointwise multiplication of their values at roots of unity.
+///
+/// The transforms are computed in `f64` whatever `T` is. The error of each
+/// coefficient is then about the machine epsilon of `f64` times the
+/// largest coefficients of the exact product.
+pub fn multiply<T: Real>(a: &[T], b: &[T]) -> Vec<T> {
+    let lift = |x: &[T]| x.iter().map(|c| Complex::from(c.to_f64())).collect::<Vec<Complex<f64>>>();
+    multiply_complex(&lift(a), &lift(b))
+        .into_iter()
+        .map(|c| T::from_f64(c.re))
+        .collect()
+}
+
+/// Like [`multiply`], for complex coefficients.
+pub fn multiply_complex<T: Real>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    let m = len.next_power_of_two();
+    let pad = |x: &[Complex<T>]| {
+        let mut x = x.to_vec();
+        x.resize(m, Complex::zero());
+        radix2(&mut x, -1.0);
+        x
+    };
+    let mut product: Vec<Complex<T>> = pad(a).iter().zip(&pad(b)).map(|(x, y)| *x * *y).collect();
+    radix2(&mut product, 1.0);
+    let scale = T::one() / T::from_i64(m as i64);
+    product.truncate(len);
+    product.into_iter().map(|x| x.scale(scale)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::{Rng, Xoshiro256};
+    use crate::scalar::schoolbook_mul;
+
+    fn random(rng: &mut Xoshiro256, n: usize) -> Vec<Complex<f64>> {
+        (0..n).map(|_| Complex::new(rng.uniform(-1.0, 1.0), rng.uniform(-1.0, 1.0))).collect()
+    }
+
+    fn max_distance(a: &[Complex<f64>], b: &[Complex<f64>]) -> f64 {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b).map(|(x, y)| (*x - *y).abs()).fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn fft_is_the_dft_for_all_lengths() {
+        let mut rng = Xoshiro256::new(24);
+        for n in [0, 1, 2, 3, 5, 7, 8, 12, 17, 64, 100] {
+            let x = random(&mut rng, n);
+            let dft: Vec<Complex<f64>> = (0..n)
+                .map(|k| x.iter().enumerate().fold(Complex::zero(), |acc, (j, c)| {
+                    acc + *c * Complex::from_polar(1.0, -2.0 * PI * ((j * k) % n) as f64 / n as f64)
+                }))
+                .collect();
+            assert!(max_distance(&fft(&x), &dft) < 1e-12, "length {}", n);
+            assert!(max_distance(&ifft(&fft(&x)), &x) < 1e-14, "length {}", n);
+        }
+    }
+
+    #[test]
+    fn multiplication_matches_schoolbook() {
+        let mut rng = Xoshiro256::new(64);
+        for (m, n) in [(FFT_THRESHOLD - 1, 200), (FFT_THRESHOLD, FFT_THRESHOLD), (300, 200), (1, 129)] {
+            let a: Vec<f64> = (0..m).map(|_| rng.uniform(-1.0, 1.0)).collect();
+            let b: Vec<f64> = (0..n).map(|_| rng.uniform(-1.0, 1.0)).collect();
+            let expected = schoolbook_mul(&a, &b);
+            let product = &Polynomial::new(a.clone()) * &Polynomial::new(b.clone());
+            for c in [multiply(&a, &b), product.into_coeffs()] {
+                assert_eq!(c.len(), expected.len());
+                assert!(c.iter().zip(&expected).all(|(x, y)| (x - y).abs() < 1e-12), "lengths {} and {}", m, n);
+            }
+            let (ca, cb) = (random(&mut rng, m), random(&mut rng, n));
+            assert!(max_distance(&multiply_complex(&ca, &cb), &schoolbook_mul(&ca, &cb)) < 1e-12);
+        }
+        assert!(multiply::<f64>(&[], &[1.0]).is_empty());
+    }
+
+    #[test]
+    fn roots_of_unity_round_trip() {
+        let mut rng = Xoshiro256::new(1);
+        let p = Polynomial::new(random(&mut rng, 10));
+        let values = eval_roots_of_unity(&p, 10);
+        let w = Complex::from_polar(1.0, 2.0 * PI * 3.0 / 10.0);
+        assert!((values[3] - p.horner(&w)).abs() < 1e-14);
+        assert!(max_distance(interpolate_roots_of_unity(&values).coeffs(), p.coeffs()) < 1e-14);
+        // fewer points than coefficients evaluate p modulo x^n - 1
+        let values = eval_roots_of_unity(&p, 4);
+        assert!((values[1] - p.horner(&Complex::i())).abs() < 1e-14);
+        assert!(eval_roots_of_unity(&p, 0).is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::lagrange::{barycentric_eval, Bterm};
+use crate::{Point, PolyGetPoints, PolyInterpolate, Scalar};
+
+// Floater-Hormann rational interpolation blends the interpolating polynomials
+// of degree d through every d + 1 consecutive nodes. The result has no real
+// poles, converges like O(h^{d+1}) for smooth functions and, unlike the
+// polynomial interpolant, behaves well on equispaced nodes. In barycentric
+// form it only differs from the Lagrange polynomial in its weights
+//   w_k = sum_{i in J_k} (-1)^i prod_{j = i, j != k}^{i + d} 1 / (x_k - x_j)
+// with J_k = { i : 0 <= i <= n - d, k - d <= i <= k } for sorted nodes.
+// See Floater and Hormann, "Barycentric rational interpolation with no poles
+// and high rates of approximation", Numer. Math. 107 (2007).
+
+/// The Floater-Hormann barycentric rational interpolant of a set of points
+/// with blending degree `d`.
+///
+/// With `d` equal to the number of points minus one it is the interpolating
+/// polynomial; with `d = 0` it is Berrut's interpolant. The points are sorted
+/// by x and must have pairwise distinct x coordinates; this is not checked.
+#[derive(Debug, Clone)]
+pub struct FloaterHormann<T = f32> {
+    d : usize,
+    bterms : Vec<Bterm<T>>
+}
+
+impl<T: Scalar + PartialOrd> FloaterHormann<T> {
+    /// The interpolant of `points` with blending degree `d`, in O(n d^2).
+    /// Degrees above the number of points minus one are lowered to it.
+    pub fn with_degree(points: &[Point<T>], d: usize) -> Self {
+        let mut points = points.to_vec();
+        points.sort_by(|p, q| p.x.partial_cmp(&q.x).unwrap_or(std::cmp::Ordering::Equal));
+        let n = points.len().saturating_sub(1);
+        let d = d.min(n);
+        let bterms = (0..points.len())
+            .map(|k| {
+                let weight = (k.saturating_sub(d)..=k.min(n - d))
+                    .map(|i| {
+                        let term = (i..=i + d)
+                            .filter(|&j| j != k)
+                            .fold(T::one(), |acc, j| acc / (points[k].x.clone() - points[j].x.clone()));
+                        if i % 2 == 0 { term } else { -term }
+                    })
+                    .fold(T::zero(), |acc, t| acc + t);
+                Bterm::new(T::one() / weight, points[k].clone())
+            })
+            .collect();
+        FloaterHormann { d, bterms }
+    }
+}
+
+impl<T> FloaterHormann<T> {
+    /// The blending degree.
+    pub fn degree(&self) -> usize {
+        self.d
+    }
+
+    /// The barycentric terms, one per interpolation node, in increasing
+    /// order of the nodes.
+    pub fn bterms(&self) -> &[Bterm<T>] {
+        &self.bterms
+    }
+}
+
+impl<T: Scalar + PartialOrd> PolyInterpolate<T> for FloaterHormann<T> {
+    /// The interpolant with blending degree 3, a good default for smooth
+    /// data on equispaced nodes.
+    fn interpolate(points: &[Point<T>]) -> Self {
+        FloaterHormann::with_degree(points, 3)
+    }
+}
+
+impl<T: Scalar> PolyGetPoints<T> for FloaterHormann<T> {
+    fn get_y(&self, x: &T) -> T {
+        barycentric_eval(&self.bterms, x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::NodeFamily;
+    use crate::{CubicSpline, LagrangePolynomial, Rational};
+
+    #[test]
+    fn full_degree_gives_the_interpolating_polynomial() {
+        let q = |n: i64| Rational::from(n);
+        let points: Vec<Point<Rational>> = [(0, 1), (1, 3), (3, -2), (4, 0), (6, 5)].iter().map(|&(x, y)| Point::new(q(x), q(y))).collect();
+        let fh = FloaterHormann::with_degree(&points, 10);
+        assert_eq!(fh.degree(), 4);
+        let lp = LagrangePolynomial::interpolate(&points);
+        for x in (-2..8).map(|n| Rational::new(2 * n + 1, 2)) {
+            assert_eq!(fh.get_y(&x), lp.get_y(&x));
+        }
+    }
+
+    #[test]
+    fn runge_function_converges_where_the_polynomial_does_not() {
+        let runge = |x: &f64| 1.0 / (1.0 + 25.0 * x * x);
+        let points: Vec<Point<f64>> = NodeFamily::Equispaced.nodes(21, -1.0, 1.0).into_iter().map(|x| Point::new(x, runge(&x))).collect();
+        let max_error = |interpolant: &dyn PolyGetPoints<f64>| (0..=1000)
+            .map(|i| -1.0 + 2e-3 * i as f64)
+            .map(|x| (interpolant.get_y(&x) - runge(&x)).abs())
+            .fold(0.0, f64::max);
+        assert!(max_error(&LagrangePolynomial::interpolate(&points)) > 10.0);
+        assert!(max_error(&CubicSpline::interpolate(&points)) < 1e-2);
+        assert!(max_error(&FloaterHormann::interpolate(&points)) < 1e-2);
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::ntt;
+use crate::Scalar;
+
+// Arithmetic in the prime field GF(p) = Z/pZ.
+// See https://en.wikipedia.org/wiki/Finite_field_arithmetic
+
+/// An element of the prime field `GF(P)`.
+///
+/// The modulus is a const parameter, so elements of different fields cannot
+/// be mixed by accident. `P` must be a prime greater than one; primality is
+/// not checked, and with a composite `P` the division of non-units is
+/// meaningless. The stored value is always reduced, i.e. in `0..P`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gf<const P: u64>(u64);
+
+impl<const P: u64> Gf<P> {
+    const VALID_MODULUS: () = assert!(P > 1, "the modulus of GF(P) must be a prime");
+
+    /// The modulus `P`.
+    pub const MODULUS: u64 = P;
+
+    /// Reduces `n` modulo `P`.
+    pub fn new(n: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_MODULUS;
+        Gf(n % P)
+    }
+
+    /// The canonical representative of the element, in `0..P`.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// `self` raised to the power `e`, by repeated squaring.
+    pub fn pow(self, e: u64) -> Self {
+        let mut base = self;
+        let mut acc = Gf(1 % P);
+        let mut e = e;
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = acc * base;
+            }
+            base = base * base;
+            e >>= 1;
+        }
+        acc
+    }
+
+    /// The multiplicative inverse, computed with the extended Euclidean
+    /// algorithm, or `None` for zero.
+    pub fn inv(self) -> Option<Self> {
+        // invariant: r_i = s_i * self (mod P)
+        let (mut r0, mut r1) = (P as i128, self.0 as i128);
+        let (mut s0, mut s1) = (0_i128, 1_i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (s0, s1) = (s1, s0 - q * s1);
+        }
+        // r0 is now gcd(P, self), which is 1 for every unit
+        if r0 != 1 {
+            return None;
+        }
+        Some(Gf(s0.rem_euclid(P as i128) as u64))
+    }
+}
+
+impl<const P: u64> From<u64> for Gf<P> {
+    fn from(n: u64) -> Self {
+        Gf::new(n)
+    }
+}
+
+impl<const P: u64> fmt::Display for Gf<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Add for Gf<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Gf(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Gf<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl<const P: u64> Mul for Gf<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Gf(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for Gf<P> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv().expect("division by zero in GF(P)")
+    }
+}
+
+impl<const P: u64> Neg for Gf<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.0 == 0 { self } else { Gf(P - self.0) }
+    }
+}
+
+impl<const P: u64> Scalar for Gf<P> {
+    fn zero() -> Self {
+        Gf::new(0)
+    }
+
+    fn one() -> Self {
+        Gf::new(1)
+    }
+
+    fn from_i64(n: i64) -> Self {
+        Gf::new((n as i128).rem_euclid(P as i128) as u64)
+    }
+
+    fn powi(&self, n: u32) -> Self {
+        self.pow(n as u64)
+    }
+
+    fn mul_coeffs(a: &[Self], b: &[Self]) -> Vec<Self> {
+        ntt::mul_coeffs(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LagrangePolynomial, NewtonPolynomial, PolyGetPoints, PolyInterpolate, Polynomial};
+
+    type F = Gf<7919>;
+
+    #[test]
+    fn field_arithmetic() {
+        assert_eq!(F::new(7920), F::new(1));
+        assert_eq!(F::new(5) - F::new(7), F::new(7917));
+        assert_eq!(-F::new(0), F::new(0));
+        assert_eq!(F::from_i64(-1), F::new(7918));
+        assert_eq!(F::new(0).inv(), None);
+        assert!((1..7919).map(F::new).all(|a| a * a.inv().unwrap() == F::new(1)));
+        // Fermat's little theorem
+        assert_eq!(F::new(1234).pow(7918), F::new(1));
+        assert_eq!(F::new(3) / F::new(3), F::new(1));
+    }
+
+    #[test]
+    fn interpolation_is_exact() {
+        let p = Polynomial::new([1, 9, 7].map(F::new));
+        let points = p.get_points(&[2, 37, 81].map(F::new));
+        let lp = LagrangePolynomial::interpolate(&points);
+        let np = NewtonPolynomial::interpolate(&points);
+        for x in (10..100).map(F::new) {
+            assert_eq!(lp.get_y(&x), p.get_y(&x));
+            assert_eq!(np.get_y(&x), p.get_y(&x));
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::newton::{newton_derivatives, newton_taylor, newton_to_monomial};
+use crate::{Point, PolyGetPoints, PolyInterpolate, Polynomial, Scalar};
+
+// See https://en.wikipedia.org/wiki/Hermite_interpolation
+//
+// A node with m known derivatives is repeated m + 1 times in the Newton form.
+// Divided differences over a repeated node are defined by continuity as
+// f[x, ..., x] = f^(k)(x) / k! for k + 1 copies of x, and all others follow
+// from the usual recurrence. These are the confluent divided differences.
+
+/// Interpolation data at a single node: the value and the first derivatives
+/// of a function at `x`.
+///
+/// `derivs[k]` is the k-th derivative, so `derivs[0]` is the value. A node
+/// with `m` entries contributes `m` conditions to the interpolant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HermiteNode<T = f32> {
+    pub x : T,
+    pub derivs : Vec<T>
+}
+
+impl<T> HermiteNode<T> {
+    pub fn new(x: T, derivs: impl Into<Vec<T>>) -> Self {
+        HermiteNode { x, derivs: derivs.into() }
+    }
+}
+
+impl<T> From<Point<T>> for HermiteNode<T> {
+    fn from(p: Point<T>) -> Self {
+        HermiteNode { x: p.x, derivs: vec![p.y] }
+    }
+}
+
+/// The Hermite interpolating polynomial: the polynomial of least degree
+/// matching given values and derivatives at a set of nodes.
+///
+/// It is stored in the Newton form over the nodes repeated by the number of
+/// conditions at each, with confluent divided differences as coefficients.
+/// The nodes must have pairwise distinct x coordinates; this is not checked.
+#[derive(Debug, Clone)]
+pub struct HermitePolynomial<T = f32> {
+    nodes : Vec<HermiteNode<T>>,
+    zs : Vec<T>, // the nodes, each repeated once per condition
+    ddiffs : Vec<T> // ddiffs[j] = f[z_0, ..., z_j]
+}
+
+impl<T: Scalar> HermitePolynomial<T> {
+    /// The interpolant matching the values and derivatives of `nodes`, built
+    /// from the confluent divided difference table in O(n^2) for `n`
+    /// conditions in total.
+    pub fn interpolate_nodes(nodes: &[HermiteNode<T>]) -> Self {
+        // owner[i] is the index of the node that z_i is a copy of
+        let owner: Vec<usize> = nodes.iter()
+            .enumerate()
+            .flat_map(|(i, node)| std::iter::repeat_n(i, node.derivs.len()))
+            .collect();
+        let zs: Vec<T> = owner.iter().map(|&i| nodes[i].x.clone()).collect();
+
+        let mut column: Vec<T> = owner.iter().map(|&i| nodes[i].derivs[0].clone()).collect();
+        let mut ddiffs = Vec::with_capacity(zs.len());
+        let mut factorial = T::one();
+        for k in 1..=zs.len() {
+            ddiffs.push(column[0].clone());
+            factorial = factorial * T::from_i64(k as i64);
+            column = column.windows(2)
+                .enumerate()
+                .map(|(i, c)| if owner[i] == owner[i + k] {
+                    nodes[owner[i]].derivs[k].clone() / factorial.clone()
+                } else {
+                    (c[1].clone() - c[0].clone()) / (zs[i + k].clone() - zs[i].clone())
+                })
+                .collect();
+        }
+        HermitePolynomial { nodes: nodes.to_vec(), zs, ddiffs }
+    }
+
+    /// The value and the first `k` derivatives of the interpolant at `x`.
+    pub fn derivatives(&self, x: &T, k: usize) -> Vec<T> {
+        newton_derivatives(&self.ddiffs, self.zs.iter(), x, k)
+    }
+
+    /// The same polynomial in the monomial basis.
+    pub fn to_monomial(&self) -> Polynomial<T> {
+        newton_to_monomial(&self.ddiffs, self.zs.iter())
+    }
+}
+
+impl<T> HermitePolynomial<T> {
+    /// The interpolation data.
+    pub fn nodes(&self) -> &[HermiteNode<T>] {
+        &self.nodes
+    }
+
+    /// The coefficients of the Newton form, the confluent divided
+    /// differences `f[z_0], f[z_0, z_1], ..., f[z_0, ..., z_n]`.
+    pub fn ddiffs(&self) -> &[T] {
+        &self.ddiffs
+    }
+}
+
+impl<T: Scalar> PolyInterpolate<T> for HermitePolynomial<T> {
+    /// Without derivative data this is the ordinary interpolant, the same as
+    /// [`NewtonPolynomial`](crate::NewtonPolynomial)'s.
+    fn interpolate(points: &[Point<T>]) -> Self {
+        let nodes: Vec<HermiteNode<T>> = points.iter().cloned().map(HermiteNode::from).collect();
+        HermitePolynomial::interpolate_nodes(&nodes)
+    }
+}
+
+impl<T: Scalar> PolyGetPoints<T> for HermitePolynomial<T> {
+    fn get_y(&self, x: &T) -> T {
+        match self.nodes.iter().find(|n| n.x == *x && !n.derivs.is_empty()) {
+            Some(node) => node.derivs[0].clone(),
+            None => newton_taylor(&self.ddiffs, self.zs.iter(), x, 0).swap_remove(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational;
+
+    fn q(n: i64) -> Rational {
+        Rational::from(n)
+    }
+
+    #[test]
+    fn positions_and_velocities_determine_a_cubic() {
+        // t^3 - 2t and its derivative 3t^2 - 2 at t = 0, 1, 2
+        let nodes = [HermiteNode::new(q(0), [q(0), q(-2)]), HermiteNode::new(q(1), [q(-1), q(1)]), HermiteNode::new(q(2), [q(4), q(10)])];
+        let hp = HermitePolynomial::interpolate_nodes(&nodes);
+        assert_eq!(hp.to_monomial(), Polynomial::new([q(0), q(-2), q(0), q(1)]));
+        assert_eq!(hp.derivatives(&q(1), 3), vec![q(-1), q(1), q(6), q(6)]);
+    }
+
+    #[test]
+    fn derivatives_are_matched_at_the_nodes() {
+        let nodes = [HermiteNode::new(q(-1), [q(2), q(0), q(3)]), HermiteNode::new(q(1), [q(1)]), HermiteNode::new(q(3), [q(0), q(-5)])];
+        let hp = HermitePolynomial::interpolate_nodes(&nodes);
+        assert_eq!(hp.to_monomial().degree(), Some(5));
+        for node in &nodes {
+            assert_eq!(hp.derivatives(&node.x, node.derivs.len() - 1), node.derivs);
+            assert_eq!(hp.to_monomial().horner(&node.x), node.derivs[0]);
+        }
+    }
+
+    #[test]
+    fn plain_values_give_the_lagrange_polynomial() {
+        let points = [Point::new(q(0), q(1)), Point::new(q(2), q(-3)), Point::new(q(5), q(4))];
+        let lp = crate::LagrangePolynomial::interpolate(&points);
+        assert_eq!(HermitePolynomial::interpolate(&points).to_monomial(), lp.to_monomial());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{Polynomial, Real};
+
+// Compensated Horner scheme: the rounding errors of every multiplication and
+// addition are recovered exactly with error-free transformations and
+// evaluated as a second polynomial, which is added back at the end.
+// See Graillat, Langlois and Louvet, "Compensated Horner Scheme" (2005) and
+// Langlois and Louvet, "How to Ensure a Faithful Polynomial Evaluation with
+// the Compensated Horner Algorithm" (2007).
+
+// s + e == a + b exactly (Knuth)
+fn two_sum<T: Real>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let z = s - a;
+    (s, (a - (s - z)) + (b - z))
+}
+
+// p + e == a * b exactly, as long as no underflow occurs
+fn two_prod<T: Real>(a: T, b: T) -> (T, T) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+// gamma_k = k u / (1 - k u) from Higham's error analysis
+fn gamma<T: Real>(k: usize, u: T) -> T {
+    let ku = T::from_i64(k as i64) * u;
+    ku / (T::one() - ku)
+}
+
+impl<T: Real> Polynomial<T> {
+    /// Evaluates the polynomial at `x` with the compensated Horner scheme.
+    ///
+    /// The result is as accurate as if Horner's rule had been carried out in
+    /// twice the working precision and then rounded, i.e. the relative error
+    /// is about `u + cond(p, x) u^2` instead of `cond(p, x) u` for the plain
+    /// [`horner`](Polynomial::horner), where `u` is the unit roundoff.
+    pub fn eval_compensated(&self, x: T) -> T {
+        self.eval_compensated_with_bound(x).0
+    }
+
+    /// Like [`eval_compensated`](Polynomial::eval_compensated), but also
+    /// returns an a posteriori bound on the absolute error of the result,
+    /// computed along with it.
+    ///
+    /// The bound is itself computed in floating point and is valid as long
+    /// as no underflow occurs and `2(n + 1)u < 1` for degree `n`.
+    pub fn eval_compensated_with_bound(&self, x: T) -> (T, T) {
+        let coeffs = self.coeffs();
+        let Some((lead, rest)) = coeffs.split_last() else {
+            return (T::zero(), T::zero());
+        };
+
+        let mut s = *lead;
+        // the error polynomial evaluated at x, and its absolute counterpart at |x|
+        let mut c = T::zero();
+        let mut b = T::zero();
+        for a in rest.iter().rev() {
+            let (p, pi) = two_prod(s, x);
+            let (t, sigma) = two_sum(p, *a);
+            s = t;
+            c = c * x + (pi + sigma);
+            b = b * x.abs() + (pi.abs() + sigma.abs());
+        }
+        let res = s + c;
+
+        let n = rest.len();
+        let u = T::epsilon() / T::from_i64(2);
+        let two = T::from_i64(2);
+        let bound = (u * res.abs() + (gamma(4 * n + 2, u) * b + two * u * u * res.abs()))
+            / (T::one() - two * T::from_i64(n as i64 + 1) * u);
+        (res, bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Polynomial;
+
+    #[test]
+    fn compensated_horner_is_accurate_near_a_multiple_root() {
+        // (x - 0.75)^7, whose expanded coefficients are exact in f64
+        let root = Polynomial::new([-0.75, 1.0]);
+        let p: Polynomial<f64> = (0..7).fold(Polynomial::one(), |acc, _| acc * root.clone());
+        for x in (1..50).map(|k| 0.75 + 1e-4 * k as f64) {
+            let exact = (x - 0.75).powi(7); // x - 0.75 is exact here
+            let (value, bound) = p.eval_compensated_with_bound(x);
+            assert!((value - exact).abs() <= bound, "x = {}", x);
+            // the condition number is up to 1e29 here, so u^2 cond(p, x) is
+            // still about 1e-3
+            assert!((value - exact).abs() <= 1e-2 * exact.abs(), "x = {}", x);
+        }
+        // while plain Horner loses all digits
+        let x = 0.7501;
+        assert!((p.horner(&x) - (x - 0.75).powi(7)).abs() > (x - 0.75).powi(7).abs());
+    }
+
+    #[test]
+    fn compensated_horner_of_the_zero_polynomial() {
+        assert_eq!(Polynomial::<f64>::zero().eval_compensated_with_bound(2.0), (0.0, 0.0));
+    }
+}
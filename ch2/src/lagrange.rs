@@ -0,0 +1,267 @@
+use crate::{Point, PolyGetPoints, PolyInterpolate, Polynomial, Scalar};
+
+// True Barycentric form is used for computing the Lagrange interpolating polynomial.
+// See https://en.wikipedia.org/wiki/Lagrange_polynomial#Barycentric_form
+
+/// A single term of the barycentric form: an interpolation node together with
+/// the product `w = prod_{k != j} (x_j - x_k)` over all other nodes.
+///
+/// Note that `w` is the reciprocal of what is usually called the barycentric
+/// weight. Other barycentric interpolants such as
+/// [`FloaterHormann`](crate::FloaterHormann) store the reciprocals of their
+/// own weights instead.
+#[derive(Debug, Clone, Copy)]
+pub struct Bterm<T = f32> {
+    w : T,
+    p : Point<T>
+}
+
+impl<T> Bterm<T> {
+    pub(crate) fn new(w: T, p: Point<T>) -> Self {
+        Bterm { w, p }
+    }
+
+    pub fn weight(&self) -> &T {
+        &self.w
+    }
+
+    pub fn point(&self) -> &Point<T> {
+        &self.p
+    }
+}
+
+/// The Lagrange interpolating polynomial of a set of points, evaluated in the
+/// true barycentric form.
+///
+/// The nodes must have pairwise distinct x coordinates; this is not checked.
+#[derive(Debug, Clone)]
+pub struct LagrangePolynomial<T = f32> {
+    bterms : Vec<Bterm<T>>
+}
+
+impl<T: Scalar> LagrangePolynomial<T> {
+    fn get_bweights(points: &[Point<T>]) -> Vec<Bterm<T>> {
+        points.iter()
+                .map(|point: &Point<T>|
+                    Bterm {
+                        p: point.clone(),
+                        w: points
+                            .iter()
+                            .map(|p: &Point<T>| point.x.clone() - p.x.clone())
+                            .filter(|p| !p.is_zero())
+                            .fold(T::one(), |acc, d| acc * d)
+                    }
+                )
+                .collect()
+    }
+
+    /// The interpolant of no points at all, i.e. the zero polynomial.
+    pub fn new() -> Self {
+        LagrangePolynomial { bterms: Vec::new() }
+    }
+
+    /// Adds a node, updating all weights in O(n).
+    pub fn add_point(&mut self, p: Point<T>) {
+        let mut w = T::one();
+        for b in self.bterms.iter_mut() {
+            let d = b.p.x.clone() - p.x.clone();
+            if !d.is_zero() {
+                b.w = b.w.clone() * d.clone();
+                w = w * -d;
+            }
+        }
+        self.bterms.push(Bterm { w, p });
+    }
+
+    /// Removes and returns the node at `index`, dividing its factor out of
+    /// all other weights in O(n).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_point(&mut self, index: usize) -> Point<T> {
+        let removed = self.bterms.remove(index).p;
+        for b in self.bterms.iter_mut() {
+            let d = b.p.x.clone() - removed.x.clone();
+            if !d.is_zero() {
+                b.w = b.w.clone() / d;
+            }
+        }
+        removed
+    }
+
+    /// The barycentric differentiation matrix `D`, which maps the values of
+    /// any polynomial of degree below n at the nodes to the values of its
+    /// derivative there.
+    ///
+    /// With barycentric weights `1 / w_j` its entries are
+    /// `D[i][j] = w_i / (w_j (x_i - x_j))` off the diagonal, and each row sums
+    /// to zero since constants have zero derivative.
+    /// See Berrut and Trefethen, "Barycentric Lagrange Interpolation" (2004), Sec. 9.
+    pub fn differentiation_matrix(&self) -> Vec<Vec<T>> {
+        self.bterms.iter()
+            .enumerate()
+            .map(|(i, bi)| {
+                let mut row: Vec<T> = self.bterms.iter()
+                    .map(|bj| {
+                        let d = bi.p.x.clone() - bj.p.x.clone();
+                        if d.is_zero() {
+                            T::zero()
+                        } else {
+                            bi.w.clone() / (bj.w.clone() * d)
+                        }
+                    })
+                    .collect();
+                row[i] = -row.iter().fold(T::zero(), |acc, d| acc + d.clone());
+                row
+            })
+            .collect()
+    }
+
+    /// The `k`-th derivative of the interpolant, as the interpolant of its
+    /// values at the same nodes, obtained by applying the differentiation
+    /// matrix `k` times in O(kn^2).
+    pub fn derivative(&self, k: usize) -> LagrangePolynomial<T> {
+        let d = self.differentiation_matrix();
+        let mut ys: Vec<T> = self.bterms.iter().map(|b| b.p.y.clone()).collect();
+        for _ in 0..k {
+            ys = d.iter()
+                .map(|row| row.iter().zip(&ys).fold(T::zero(), |acc, (d, y)| acc + d.clone() * y.clone()))
+                .collect();
+        }
+        let bterms = self.bterms.iter()
+            .zip(ys)
+            .map(|(b, y)| Bterm { w: b.w.clone(), p: Point { x: b.p.x.clone(), y } })
+            .collect();
+        LagrangePolynomial { bterms }
+    }
+
+    /// The definite integral of the interpolant over `[a, b]`, computed
+    /// exactly from its monomial form.
+    pub fn integrate(&self, a: &T, b: &T) -> T {
+        self.to_monomial().integrate(a, b)
+    }
+
+    /// The same polynomial in the monomial basis.
+    ///
+    /// With `l(x) = prod_j (x - x_j)` the interpolant is
+    /// `sum_j y_j / w_j * l(x) / (x - x_j)`, so `l` is expanded once and each
+    /// term is obtained from it by exact division by a linear factor.
+    pub fn to_monomial(&self) -> Polynomial<T> {
+        let factor = |b: &Bterm<T>| Polynomial::new(vec![-b.p.x.clone(), T::one()]);
+        let l = self.bterms.iter()
+            .fold(Polynomial::one(), |acc, b| &acc * &factor(b));
+        self.bterms.iter()
+            .fold(Polynomial::zero(), |acc, b| {
+                let lj = l.div_rem(&factor(b)).0;
+                &acc + &lj.scale(&(b.p.y.clone() / b.w.clone()))
+            })
+    }
+}
+
+impl<T> LagrangePolynomial<T> {
+    /// The barycentric terms, one per interpolation node, in insertion order.
+    pub fn bterms(&self) -> &[Bterm<T>] {
+        &self.bterms
+    }
+}
+
+impl<T: Scalar> Default for LagrangePolynomial<T> {
+    fn default() -> Self {
+        LagrangePolynomial::new()
+    }
+}
+
+impl<T: Scalar> PolyGetPoints<T> for LagrangePolynomial<T> {
+    fn get_y(&self, x: &T) -> T {
+        barycentric_eval(&self.bterms, x)
+    }
+}
+
+impl<T: Scalar> PolyInterpolate<T> for LagrangePolynomial<T> {
+    fn interpolate(points: &[Point<T>]) -> Self {
+        LagrangePolynomial { bterms: LagrangePolynomial::get_bweights(points) }
+    }
+}
+
+// Evaluates the barycentric form `sum_j y_j / (w_j (x - x_j)) / sum_j 1 / (w_j (x - x_j))`
+// of `bterms` at `x`. Any weights give a rational function through the nodes;
+// the weights of LagrangePolynomial make it the interpolating polynomial.
+pub(crate) fn barycentric_eval<T: Scalar>(bterms: &[Bterm<T>], x: &T) -> T {
+    // check if this is one of the interpolation points
+    if let Some(bweight) = bterms
+        .iter()
+        .find(|b| b.p.x == *x) {
+            bweight.p.y.clone()
+    }
+    else if bterms.is_empty() {
+        T::zero()
+    }
+    // else compute y
+    else {
+        let terms: (T, T) = bterms
+            .iter()
+            .fold((T::zero(), T::zero()),
+                |acc, bterm| {
+                    let temp = (x.clone() - bterm.p.x.clone()) * bterm.w.clone();
+                    (acc.0 + bterm.p.y.clone() / temp.clone(), acc.1 + T::one() / temp)
+                }
+            );
+
+        terms.0 / terms.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{book_points, q};
+    use crate::{Gf, Rational};
+
+    #[test]
+    fn to_monomial_recovers_the_polynomial() {
+        let (p, points) = book_points();
+        assert_eq!(LagrangePolynomial::interpolate(&points).to_monomial(), p);
+        let p = Polynomial::new([1, 9, 7].map(Gf::<7919>::new));
+        let points = p.get_points(&[2, 37, 81].map(Gf::new));
+        assert_eq!(LagrangePolynomial::interpolate(&points).to_monomial(), p);
+    }
+
+    #[test]
+    fn values_are_exact() {
+        let (p, points) = book_points();
+        let lp = LagrangePolynomial::interpolate(&points);
+        for x in (10..100).map(Rational::from) {
+            assert_eq!(lp.get_y(&x), p.get_y(&x));
+        }
+    }
+
+    #[test]
+    fn points_can_be_added_and_removed() {
+        let (p, points) = book_points();
+        let mut lp = LagrangePolynomial::new();
+        for pt in &points {
+            lp.add_point(pt.clone());
+        }
+        assert_eq!(lp.to_monomial(), p);
+        let extra = Point::new(q("5"), q("1"));
+        lp.add_point(extra.clone());
+        assert_ne!(lp.to_monomial(), p);
+        assert_eq!(lp.remove_point(3), extra);
+        assert_eq!(lp.to_monomial(), p);
+        assert_eq!(lp.remove_point(0), points[0]);
+        assert_eq!(lp.to_monomial(), LagrangePolynomial::interpolate(&points[1..]).to_monomial());
+    }
+
+    #[test]
+    fn derivatives_and_integrals_match_the_polynomial() {
+        let (p, points) = book_points();
+        let lp = LagrangePolynomial::interpolate(&points);
+        let x = q("50");
+        for k in 0..=3 {
+            assert_eq!(lp.derivative(k).get_y(&x), p.derivative(k).horner(&x), "derivative {}", k);
+        }
+        assert_eq!(lp.integrate(&q("0"), &q("1")), q("53/6"));
+        assert_eq!(p.integrate(&q("0"), &q("1")), q("53/6"));
+    }
+}
@@ -0,0 +1,138 @@
+// See Chapter 2 of A Programmer's Introduction to Mathematics (https://pimbook.org)
+
+//! Polynomial interpolation from Chapter 2 of *A Programmer's Introduction to
+//! Mathematics*.
+//!
+//! The crate provides an owned monomial-basis [`Polynomial`] with ring
+//! arithmetic, together with two interpolators, [`LagrangePolynomial`]
+//! (barycentric form) and [`NewtonPolynomial`] (divided differences).
+//! [`HermitePolynomial`] additionally matches derivatives at the nodes.
+//! For data that a single polynomial fits badly there are the piecewise
+//! [`CubicSpline`] and the rational [`FloaterHormann`] interpolants.
+//! Interpolators are built from a slice of [`Point`]s through
+//! [`PolyInterpolate`], and everything that can be evaluated implements
+//! [`PolyGetPoints`]. For a one-off evaluation, [`neville::evaluate`] avoids
+//! building an interpolant at all.
+//!
+//! All types are generic over a [`Scalar`] field and default to `f32`, so the
+//! same code interpolates over `f64` or over exact number types such as the
+//! prime field elements [`Gf`] and the arbitrary precision [`Rational`]s.
+//!
+//! [`fft`] evaluates and interpolates at the roots of unity in O(n log n),
+//! which also makes multiplication of long floating point polynomials fast;
+//! [`ntt`] does the same exactly over prime fields such as
+//! `Gf<998244353>`.
+//!
+//! On top of interpolation over finite fields, [`shamir`] implements
+//! Shamir's secret sharing.
+//!
+//! Node sets for interpolation are generated by [`nodes`]; see
+//! `examples/runge.rs` for why the choice matters, and [`conditioning`]
+//! for how much a node set amplifies errors in the data.
+//!
+//! The invariants of interpolation are checked on random inputs by the
+//! small property-based testing harness in [`property`].
+
+// Implements the binary operators `$imp` for owned operands of type `$t` by
+// forwarding to the implementations for references, e.g.
+// `forward_binop!(impl[T: Scalar] Polynomial<T>; Add add, Sub sub)`.
+macro_rules! forward_binop {
+    (impl[$($gen:tt)*] $t:ty;) => {};
+    (impl[$($gen:tt)*] $t:ty; $imp:ident $method:ident $(, $($rest:tt)*)?) => {
+        impl<$($gen)*> $imp for $t {
+            type Output = $t;
+
+            fn $method(self, rhs: $t) -> $t {
+                (&self).$method(&rhs)
+            }
+        }
+
+        forward_binop!(impl[$($gen)*] $t; $($($rest)*)?);
+    };
+}
+
+pub mod aberth;
+pub mod bigint;
+mod calculus;
+pub mod complex;
+pub mod conditioning;
+mod division;
+pub mod fft;
+pub mod floater_hormann;
+pub mod gf;
+pub mod hermite;
+mod horner;
+pub mod lagrange;
+pub mod monomial;
+pub mod neville;
+pub mod newton;
+pub mod nodes;
+pub mod ntt;
+pub mod points;
+pub mod property;
+pub mod rational;
+pub mod rng;
+pub mod scalar;
+pub mod shamir;
+pub mod spline;
+pub mod sturm;
+#[cfg(test)]
+mod test_util;
+pub mod validation;
+
+pub use aberth::{ComplexRoot, RootFinder};
+pub use bigint::BigInt;
+pub use complex::Complex;
+pub use floater_hormann::FloaterHormann;
+pub use gf::Gf;
+pub use hermite::{HermiteNode, HermitePolynomial};
+pub use lagrange::{Bterm, LagrangePolynomial};
+pub use monomial::Polynomial;
+pub use newton::{DividedDifferences, NewtonPolynomial};
+pub use points::Point;
+pub use rational::Rational;
+pub use scalar::{Real, Scalar};
+pub use spline::{CubicSpline, SplineBoundary, SplineSegment};
+pub use sturm::RealRoot;
+pub use validation::{InterpolationError, Validation};
+
+/// Construction of an interpolating polynomial from a set of points.
+///
+/// [`interpolate`](PolyInterpolate::interpolate) assumes that the x
+/// coordinates of `points` are pairwise distinct and produces a meaningless
+/// result otherwise; [`try_interpolate`](PolyInterpolate::try_interpolate)
+/// checks its input first.
+pub trait PolyInterpolate<T: Scalar = f32> {
+    fn interpolate(points: &[Point<T>]) -> Self;
+
+    /// Interpolates `points` after checking them with the default
+    /// [`Validation`].
+    fn try_interpolate(points: &[Point<T>]) -> Result<Self, InterpolationError>
+    where
+        Self: Sized,
+    {
+        Self::try_interpolate_with(points, &Validation::default())
+    }
+
+    /// Interpolates `points` after checking them with `validation`.
+    fn try_interpolate_with(points: &[Point<T>], validation: &Validation) -> Result<Self, InterpolationError>
+    where
+        Self: Sized,
+    {
+        validation.check(points)?;
+        Ok(Self::interpolate(points))
+    }
+}
+
+/// Evaluation of a polynomial at arbitrary x coordinates.
+pub trait PolyGetPoints<T: Scalar = f32> {
+    /// Returns the value of the polynomial at `x`.
+    fn get_y(&self, x: &T) -> T;
+
+    /// Evaluates the polynomial at every x in `xs`.
+    fn get_points(&self, xs: &[T]) -> Vec<Point<T>> {
+        xs.iter()
+          .map(|x| Point{x: x.clone(), y: self.get_y(x)})
+          .collect()
+    }
+}
@@ -1,188 +1,96 @@
 // See Chapter 2 of A Programmer's Introduction to Mathematics (https://pimbook.org)
 
-// TODO:
-//  1. Add option for random generation of Polynomial coefficients
-//  2. Add option for random generation of test points
-//  3. Rewrite this as a library with proper interface and structuring
-//  4. Write tests
-//  5. Add checks (e.g., interpolation points are actually different)
-//  6. Make NewtonPolynomial::ddiff more efficient
-
-#[derive(Debug, PartialEq)]
-struct Point {
-    x : f32,
-    y : f32
-}
-
-trait PolyInterpolate<'a> {
-    fn interpolate(points: &'a [Point]) -> Self;
-}
-
-trait PolyGetPoints {
-
-    fn get_y(&self, x: &f32) -> f32;
-
-    fn get_points(&self, xs : &[f32]) -> Vec<Point> {
-        xs.iter()
-          .map(|x| Point{x: *x, y: self.get_y(x)})
-          .collect()
-    }
-}
+use ch2::conditioning::ConditioningReport;
+use ch2::nodes::NodeFamily;
+use ch2::{CubicSpline, FloaterHormann, LagrangePolynomial, NewtonPolynomial, Point, PolyGetPoints, PolyInterpolate, Polynomial, Rational};
 
-#[derive(Debug)]
-struct Polynomial<'a>(&'a[f32]);
+fn main() {
+    let p: Polynomial = Polynomial::new([1.9, 9.2, 7.0]);
+    let points = p.get_points(&[1.8,37.2,80.9]);
 
-impl<'a> Polynomial<'a> {
-    fn new(coeffs : &'a[f32]) -> Self {
-        Polynomial(coeffs)
-    }
-}
+    let lp = LagrangePolynomial::interpolate(&points);
+    let np = NewtonPolynomial::interpolate(&points);
 
-impl PolyGetPoints for Polynomial<'_> {
-    fn get_y(&self, x: &f32) -> f32 {
-        self.0.iter()
-            .enumerate()
-            .fold(0.0, |acc, c| acc + x.powi(c.0 as i32) * (c.1))
-    }
-}
+    let test_points: Vec<f32> = (10u8..100u8).map(f32::from).collect();
 
-// True Barycentric form is used for computing the Lagrange interpolating polynomial.
-// See https://en.wikipedia.org/wiki/Lagrange_polynomial#Barycentric_form
+    let count_lp = p.get_points(&test_points)
+        .into_iter()
+        .zip(lp.get_points(&test_points))
+        .filter(|x| (x.0.y - x.1.y).abs() > 0.01)
+        .count();
+    println!("{}", count_lp);
 
-#[derive(Debug)]
-struct Bterm<'a> {
-    w : f32,
-    p : &'a Point
-}
+    let count_np = p.get_points(&test_points)
+        .into_iter()
+        .zip(np.get_points(&test_points))
+        .filter(|x| (x.0.y - x.1.y).abs() > 0.05)
+        .count();
+    println!("{}", count_np);
 
-#[derive(Debug)]
-struct LagrangePolynomial<'a> {
-    bterms : Vec<Bterm<'a>>
-}
+    // the same interpolation over the rationals has no rounding errors at all
+    let q = |s: &str| s.parse::<Rational>().unwrap();
+    let p = Polynomial::new([q("1.9"), q("9.2"), q("7")]);
+    let points = p.get_points(&[q("1.8"), q("37.2"), q("80.9")]);
+    println!("exact interpolant: {}", NewtonPolynomial::interpolate(&points).to_monomial());
 
-impl<'a> LagrangePolynomial<'a> {
-    fn get_bweights(points: &'a [Point]) -> Vec<Bterm> {
-        points.iter()
-                .map(|point: &Point|
-                    Bterm {
-                        p: point,
-                        w: points
-                            .iter()
-                            .map(|p: &Point | point.x - p.x )
-                            .filter(|p| *p != 0_f32)
-                            .product::<f32>()
-                    }
-                )
-                .collect()
+    // equispaced nodes amplify errors in the data, Chebyshev nodes do not
+    for family in [NodeFamily::Equispaced, NodeFamily::ChebyshevSecond] {
+        let points: Vec<Point<f64>> = family.nodes(21, -1.0, 1.0).into_iter().map(|x| Point::new(x, 0.0)).collect();
+        println!("{} nodes: {}", family, ConditioningReport::analyze(&points, 1e3).unwrap());
     }
-}
 
-impl PolyGetPoints for LagrangePolynomial<'_> {
-    fn get_y(&self, x:&f32) -> f32 {
-        // check if this is one of the interpolation points
-        if let Some(bweight) = self.bterms
-            .iter()
-            .find(|b| b.p.x == *x) {
-                bweight.p.y
-        }
-        // else compute y
-        else {
-            let terms: (f32, f32) = self.bterms
-                .iter()
-                .fold((0.0, 0.0),
-                    |acc, bterm| {
-                        let temp = (x - bterm.p.x) * bterm.w;
-                        (acc.0 + (bterm.p.y / temp), acc.1 + (1.0 / temp))
-                    }
-                );
-
-            terms.0 / terms.1
-        }
+    // on Runge's function splines and rational interpolants converge where the polynomial oscillates
+    let runge = |x: &f64| 1.0 / (1.0 + 25.0 * x * x);
+    let points: Vec<Point<f64>> = NodeFamily::Equispaced.nodes(21, -1.0, 1.0).into_iter().map(|x| Point::new(x, runge(&x))).collect();
+    let interpolants: [(&str, &dyn PolyGetPoints<f64>); 3] = [
+        ("polynomial", &LagrangePolynomial::interpolate(&points)),
+        ("cubic spline", &CubicSpline::interpolate(&points)),
+        ("Floater-Hormann", &FloaterHormann::interpolate(&points)),
+    ];
+    for (name, interpolant) in interpolants {
+        let err = (0..=1000)
+            .map(|i| -1.0 + 2e-3 * i as f64)
+            .map(|x| (interpolant.get_y(&x) - runge(&x)).abs())
+            .fold(0.0, f64::max);
+        println!("max error of the {} on Runge's function: {:.3e}", name, err);
     }
-}
 
-impl<'a> PolyInterpolate<'a> for LagrangePolynomial<'a> {
-    fn interpolate(points: &'a [Point]) -> Self {
-        LagrangePolynomial { bterms: LagrangePolynomial::get_bweights(points) }
-    }
+    #[cfg(unix)]
+    shamir_demo::run();
 }
 
-// See https://en.wikipedia.org/wiki/Newton_polynomial
+// Shamir's secret sharing needs unpredictable coefficients, and the crate has
+// no cryptographically secure generator of its own, so the demo reads the
+// operating system's from /dev/urandom, where that exists
+#[cfg(unix)]
+mod shamir_demo {
+    use std::fs::File;
+    use std::io::Read;
 
-#[derive(Debug)]
-struct NewtonPolynomial<'a> {
-    points : &'a [Point],
-    ddiffs : Vec<f32> // divided differences
-}
+    use ch2::rng::{CryptoRng, Rng};
+    use ch2::shamir;
 
-impl NewtonPolynomial<'_> {
-    fn get_ddiffs(points : &[Point]) -> Vec<f32> {
-        points.iter()
-            .enumerate()
-            .map(|j| Self::ddiff(0, j.0 as i32, points))
-            .collect()
-    }
+    struct OsRng(File);
 
-    // compute divided differences with naive recursion
-    fn ddiff(i: i32, j: i32, points: &[Point]) -> f32 {
-        match (i - j).abs() {
-            0 => points[i as usize].y,
-            1 => (points[j as usize].y - points[i as usize].y) / (points[j as usize].x - points[i as usize].x),
-            _ => (Self::ddiff(i + 1, j, points) - Self::ddiff(i, j - 1, points)) / (points[j as usize].x - points[i as usize].x)
+    impl Rng for OsRng {
+        fn next_u64(&mut self) -> u64 {
+            let mut bytes = [0; 8];
+            self.0.read_exact(&mut bytes).expect("reading /dev/urandom failed");
+            u64::from_le_bytes(bytes)
         }
     }
-}
-
-impl<'a> PolyInterpolate<'a> for NewtonPolynomial<'a> {
-    fn interpolate(points: &'a [Point]) -> Self {
-        NewtonPolynomial {points : points, ddiffs : NewtonPolynomial::get_ddiffs(points)}
-    }
-}
 
-impl PolyGetPoints for NewtonPolynomial<'_> {
-
-    fn get_y(&self, x: &f32) -> f32 {
-        if let Some(point) = self.points
-            .iter()
-            .find(|p| p.x == *x) {
-                point.y
-        } else {
-            self.ddiffs[1..].iter()
-                .zip(self.points.iter()
-                        .map(|p| *x - p.x )
-                        .scan(1_f32, |acc, x| {
-                            *acc = *acc * x;
-                            Some(*acc)
-                        }) // Newton basis polynomials
-                )
-                .map(|z| *z.0 * z.1)
-                .sum::<f32>()
-            + self.ddiffs[0] // first divided difference in the sum doesn't have a multiplier
-        }
+    impl CryptoRng for OsRng {}
+
+    // share a secret between five parties so that any three can recover it
+    pub fn run() {
+        const M61: u64 = (1 << 61) - 1;
+        let Ok(file) = File::open("/dev/urandom") else {
+            println!("no /dev/urandom, skipping the secret sharing demo");
+            return;
+        };
+        let shares = shamir::split::<M61, _>(271828, 3, 5, &mut OsRng(file)).unwrap();
+        let subset = [shares[4], shares[0], shares[2]];
+        println!("secret recovered from shares 5, 1 and 3: {}", shamir::combine(&subset).unwrap());
     }
 }
-
-
-fn main() {
-    let p: Polynomial = Polynomial::new(&[1.9, 9.2, 7.0]);
-    let points = p.get_points(&[1.8,37.2,80.9]);
-
-    let lp = LagrangePolynomial::interpolate(&points);
-    let np = NewtonPolynomial::interpolate(&points);
-
-    let test_points: Vec<f32> = (10u8..100u8).map(f32::from).collect();
-
-    let count_lp = p.get_points(&test_points)
-        .into_iter()
-        .zip(lp.get_points(&test_points).into_iter())
-        .filter(|x| (x.0.y - x.1.y).abs() > 0.01)
-        .count();
-    println!("{}", count_lp);
-
-    let count_np = p.get_points(&test_points)
-        .into_iter()
-        .zip(np.get_points(&test_points).into_iter())
-        .filter(|x| (x.0.y - x.1.y).abs() > 0.05)
-        .count();
-    println!("{}", count_np);
-}
\ No newline at end of file
@@ -0,0 +1,225 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{PolyGetPoints, Scalar};
+
+/// A polynomial in the monomial basis.
+///
+/// `coeffs[i]` is the coefficient of `x^i`, so `[1.9, 9.2, 7.0]` is
+/// `1.9 + 9.2x + 7x^2`. Trailing zero coefficients are always trimmed, so the
+/// last coefficient is nonzero and the zero polynomial has no coefficients at
+/// all. Thanks to this, derived equality is equality of polynomials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial<T = f32>(Vec<T>);
+
+impl<T: Scalar> Polynomial<T> {
+    /// The polynomial with the given coefficients, in order of increasing
+    /// degree. Trailing zeros are dropped.
+    pub fn new(coeffs : impl Into<Vec<T>>) -> Self {
+        let mut coeffs = coeffs.into();
+        while coeffs.last().is_some_and(|c| c.is_zero()) {
+            coeffs.pop();
+        }
+        Polynomial(coeffs)
+    }
+
+    pub fn zero() -> Self {
+        Polynomial(Vec::new())
+    }
+
+    pub fn one() -> Self {
+        Polynomial::constant(T::one())
+    }
+
+    pub fn constant(c: T) -> Self {
+        Polynomial::new(vec![c])
+    }
+
+    /// The single term `c x^k`.
+    pub fn monomial(c: T, k: usize) -> Self {
+        let mut coeffs = vec![T::zero(); k];
+        coeffs.push(c);
+        Polynomial::new(coeffs)
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's rule
+    /// `c_0 + x(c_1 + x(c_2 + ...))`, using n multiplications and additions.
+    ///
+    /// See [`Polynomial::eval_compensated`] for a more accurate evaluation
+    /// over floating point numbers.
+    pub fn horner(&self, x: &T) -> T {
+        self.0.iter()
+            .rev()
+            .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    /// Multiplies every coefficient by `c`.
+    pub fn scale(&self, c: &T) -> Self {
+        Polynomial::new(self.0.iter().map(|a| a.clone() * c.clone()).collect::<Vec<T>>())
+    }
+}
+
+impl<T> Polynomial<T> {
+    /// Coefficients in order of increasing degree, without trailing zeros.
+    pub fn coeffs(&self) -> &[T] {
+        &self.0
+    }
+
+    pub fn into_coeffs(self) -> Vec<T> {
+        self.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.0.len().checked_sub(1)
+    }
+
+    /// The coefficient of the highest power, or `None` for the zero polynomial.
+    pub fn leading_coeff(&self) -> Option<&T> {
+        self.0.last()
+    }
+}
+
+impl<T: Scalar> Default for Polynomial<T> {
+    fn default() -> Self {
+        Polynomial::zero()
+    }
+}
+
+impl<T: Scalar + fmt::Display> fmt::Display for Polynomial<T> {
+    /// Formats as `c_0 + c_1*x + c_2*x^2 + ...`, leaving out zero terms.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (i, c) in self.0.iter().enumerate().filter(|(_, c)| !c.is_zero()) {
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+            match i {
+                0 => write!(f, "{}", c)?,
+                1 => write!(f, "{}*x", c)?,
+                _ => write!(f, "{}*x^{}", c, i)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Scalar> PolyGetPoints<T> for Polynomial<T> {
+    /// Evaluates with Horner's rule, see [`Polynomial::horner`].
+    fn get_y(&self, x: &T) -> T {
+        self.horner(x)
+    }
+}
+
+// coefficient-wise combination of two polynomials of possibly different length
+fn zip_coeffs<T: Scalar>(a: &[T], b: &[T], f: impl Fn(T, T) -> T) -> Vec<T> {
+    (0..a.len().max(b.len()))
+        .map(|i| f(a.get(i).cloned().unwrap_or_else(T::zero), b.get(i).cloned().unwrap_or_else(T::zero)))
+        .collect()
+}
+
+impl<T: Scalar> Add for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+        Polynomial::new(zip_coeffs(&self.0, &rhs.0, |a, b| a + b))
+    }
+}
+
+impl<T: Scalar> Sub for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+        Polynomial::new(zip_coeffs(&self.0, &rhs.0, |a, b| a - b))
+    }
+}
+
+impl<T: Scalar> Mul for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+        Polynomial::new(T::mul_coeffs(&self.0, &rhs.0))
+    }
+}
+
+impl<T: Scalar> Mul<&T> for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: &T) -> Polynomial<T> {
+        self.scale(rhs)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: T) -> Polynomial<T> {
+        self.scale(&rhs)
+    }
+}
+
+impl<T: Scalar> Neg for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        Polynomial(self.0.iter().map(|c| -c.clone()).collect())
+    }
+}
+
+impl<T: Scalar> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        -&self
+    }
+}
+
+forward_binop!(impl[T: Scalar] Polynomial<T>; Add add, Sub sub, Mul mul);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational;
+
+    fn poly(coeffs: &[i64]) -> Polynomial<Rational> {
+        Polynomial::new(coeffs.iter().map(|&c| Rational::from(c)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn coefficients_are_trimmed() {
+        assert_eq!(poly(&[1, 2, 0, 0]).coeffs().len(), 2);
+        assert_eq!(poly(&[0, 0]), Polynomial::zero());
+        assert_eq!(Polynomial::<Rational>::zero().degree(), None);
+        assert_eq!(poly(&[5]).degree(), Some(0));
+    }
+
+    #[test]
+    fn ring_arithmetic() {
+        let (a, b, c) = (poly(&[1, 2]), poly(&[-3, 0, 1]), poly(&[4, -1, 0, 2]));
+        assert_eq!(&a + &b, poly(&[-2, 2, 1]));
+        assert_eq!(&a - &a, Polynomial::zero());
+        assert_eq!(&a * &b, poly(&[-3, -6, 1, 2]));
+        assert_eq!(&(&a + &b) * &c, &(&a * &c) + &(&b * &c));
+        assert_eq!(-&a, poly(&[-1, -2]));
+        assert_eq!(&a * &Rational::from(3), poly(&[3, 6]));
+    }
+
+    #[test]
+    fn nodes_are_the_roots_of_their_linear_factors() {
+        let xs: Vec<Rational> = ["1.8", "37.2", "80.9"].iter().map(|s| s.parse().unwrap()).collect();
+        let l = xs.iter()
+            .map(|x| Polynomial::new([-x.clone(), Rational::from(1)]))
+            .fold(Polynomial::one(), |acc, f| acc * f);
+        assert_eq!(l.degree(), Some(3));
+        assert!(xs.iter().all(|x| l.get_y(x).is_zero()));
+        assert!(!l.get_y(&Rational::from(2)).is_zero());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Point, Scalar};
+
+// Neville's algorithm evaluates the interpolating polynomial at a single x
+// without computing any coefficients. Its tableau holds the values
+// P_{i..j}(x) of the interpolants of the nodes i..j, which satisfy
+//   P_{i..j}(x) = ((x - x_j) P_{i..j-1}(x) + (x_i - x) P_{i+1..j}(x)) / (x_i - x_j)
+// starting from P_{i..i}(x) = y_i. See https://en.wikipedia.org/wiki/Neville%27s_algorithm
+
+/// The value at `x` of the interpolating polynomial of `points`, together
+/// with an estimate of its error, in O(n^2) time and O(n) memory.
+///
+/// The estimate is `P(x) - P'(x)`, where `P'` interpolates all points but
+/// the last; for a single point it is the value itself. Order the points by
+/// distance from `x` for the estimate to be meaningful. No points give zero.
+/// The nodes must have pairwise distinct x coordinates; this is not checked.
+///
+/// Evaluating at `x = 0` samples `(h, A(h))` of a quantity `A` computed with
+/// step `h` is Richardson extrapolation of `A` to `h = 0`.
+pub fn evaluate<T: Scalar>(points: &[Point<T>], x: &T) -> (T, T) {
+    let n = points.len();
+    let mut p: Vec<T> = points.iter().map(|p| p.y.clone()).collect();
+    // p[0] is P_{0..k} after step k
+    let mut previous = T::zero();
+    for k in 1..n {
+        previous = p[0].clone();
+        for i in 0..n - k {
+            let (xi, xj) = (&points[i].x, &points[i + k].x);
+            p[i] = ((x.clone() - xj.clone()) * p[i].clone() + (xi.clone() - x.clone()) * p[i + 1].clone())
+                / (xi.clone() - xj.clone());
+        }
+    }
+    match p.into_iter().next() {
+        Some(value) => (value.clone(), value - previous),
+        None => (T::zero(), T::zero()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LagrangePolynomial, PolyGetPoints, PolyInterpolate, Rational};
+
+    #[test]
+    fn matches_the_interpolating_polynomial() {
+        let q = |n: i64, d: i64| Rational::new(n, d);
+        let points = [Point::new(q(0, 1), q(1, 1)), Point::new(q(1, 2), q(-2, 3)), Point::new(q(2, 1), q(5, 1)), Point::new(q(3, 1), q(0, 1))];
+        let lp = LagrangePolynomial::interpolate(&points);
+        let without_last = LagrangePolynomial::interpolate(&points[..3]);
+        for x in [q(-1, 1), q(1, 3), q(7, 2)] {
+            let (value, estimate) = evaluate(&points, &x);
+            assert_eq!(value, lp.get_y(&x));
+            assert_eq!(estimate, lp.get_y(&x) - without_last.get_y(&x));
+        }
+        assert_eq!(evaluate::<Rational>(&[], &q(1, 1)), (q(0, 1), q(0, 1)));
+        assert_eq!(evaluate(&points[..1], &q(5, 1)), (q(1, 1), q(1, 1)));
+    }
+
+    #[test]
+    fn richardson_extrapolation() {
+        // central differences of exp at 1 are a series in h^2
+        let diff = |h: f64| ((1.0 + h).exp() - (1.0 - h).exp()) / (2.0 * h);
+        let samples: Vec<Point<f64>> = (0..4).map(|k| 0.1 / (1 << k) as f64).map(|h| Point::new(h * h, diff(h))).collect();
+        let (value, estimate) = evaluate(&samples, &0.0);
+        let e = 1_f64.exp();
+        assert!((diff(0.1 / 8.0) - e).abs() > 1e-5);
+        assert!((value - e).abs() < 1e-12);
+        assert!(estimate.abs() < 1e-10);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::{Point, PolyGetPoints, PolyInterpolate, Polynomial, Scalar};
+
+// See https://en.wikipedia.org/wiki/Newton_polynomial
+
+/// The Newton form of the interpolating polynomial of a set of points.
+///
+/// `ddiffs[j]` is the divided difference `f[x_0, ..., x_j]`, i.e. the
+/// coefficient of the j-th Newton basis polynomial
+/// `(x - x_0) ... (x - x_{j-1})`. The nodes must have pairwise distinct x
+/// coordinates; this is not checked.
+///
+/// Besides the top diagonal of the divided difference table, which holds the
+/// coefficients, the bottom diagonal `f[x_n], f[x_{n-1}, x_n], ...` is kept
+/// so that points can be appended in O(n).
+#[derive(Debug, Clone)]
+pub struct NewtonPolynomial<T = f32> {
+    points : Vec<Point<T>>,
+    ddiffs : Vec<T>, // divided differences
+    last : Vec<T> // last[k] = f[x_{n-k}, ..., x_n]
+}
+
+impl<T: Scalar> NewtonPolynomial<T> {
+    /// The interpolant of no points at all, i.e. the zero polynomial.
+    pub fn new() -> Self {
+        NewtonPolynomial { points: Vec::new(), ddiffs: Vec::new(), last: Vec::new() }
+    }
+
+    /// Appends a node, updating the interpolant in O(n).
+    ///
+    /// Only the new bottom diagonal of the divided difference table is
+    /// computed, and its last entry is the new Newton coefficient.
+    pub fn add_point(&mut self, p: Point<T>) {
+        let n = self.points.len();
+        let mut last = Vec::with_capacity(n + 1);
+        last.push(p.y.clone());
+        for k in 1..=n {
+            let d = (last[k - 1].clone() - self.last[k - 1].clone()) / (p.x.clone() - self.points[n - k].x.clone());
+            last.push(d);
+        }
+        self.ddiffs.push(last[n].clone());
+        self.last = last;
+        self.points.push(p);
+    }
+
+    /// Removes and returns the node at `index`.
+    ///
+    /// Removing the most recently added node undoes [`add_point`] in O(n).
+    /// Since the Newton coefficients depend on the order of the nodes, any
+    /// other node can only be removed by rebuilding the interpolant from the
+    /// remaining nodes, in O(n^2).
+    ///
+    /// [`add_point`]: NewtonPolynomial::add_point
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_point(&mut self, index: usize) -> Point<T> {
+        if index + 1 != self.points.len() {
+            let p = self.points.remove(index);
+            *self = Self::interpolate(&self.points);
+            return p;
+        }
+        let p = self.points.pop().expect("index out of bounds");
+        self.ddiffs.pop();
+        // invert the recurrence in add_point
+        let n = self.points.len();
+        self.last = (1..=n)
+            .map(|k| self.last[k - 1].clone() - self.last[k].clone() * (p.x.clone() - self.points[n - k].x.clone()))
+            .collect();
+        p
+    }
+
+    /// The full divided difference table of `points`, column by column.
+    pub fn ddiff_table(points: &[Point<T>]) -> DividedDifferences<'_, T> {
+        DividedDifferences { points, column: points.iter().map(|p| p.y.clone()).collect(), order: 0 }
+    }
+
+    /// The value and the first `k` derivatives of the interpolant at `x`,
+    /// evaluated directly on the Newton form in O(nk).
+    pub fn derivatives(&self, x: &T, k: usize) -> Vec<T> {
+        newton_derivatives(&self.ddiffs, self.points.iter().map(|p| &p.x), x, k)
+    }
+
+    /// The definite integral of the interpolant over `[a, b]`, computed
+    /// exactly from its monomial form.
+    pub fn integrate(&self, a: &T, b: &T) -> T {
+        self.to_monomial().integrate(a, b)
+    }
+
+    /// The same polynomial in the monomial basis.
+    ///
+    /// The Newton form is expanded from the inside out, in the same nested
+    /// way that Horner's rule evaluates it:
+    /// `c_0 + (x - x_0)(c_1 + (x - x_1)(c_2 + ...))`.
+    pub fn to_monomial(&self) -> Polynomial<T> {
+        newton_to_monomial(&self.ddiffs, self.points.iter().map(|p| &p.x))
+    }
+}
+
+// Expands the Newton form with coefficients `ddiffs` and nodes `xs` into the
+// monomial basis; see NewtonPolynomial::to_monomial.
+pub(crate) fn newton_to_monomial<'a, T, I>(ddiffs: &[T], xs: I) -> Polynomial<T>
+where
+    T: Scalar + 'a,
+    I: DoubleEndedIterator<Item = &'a T> + ExactSizeIterator,
+{
+    ddiffs.iter()
+        .zip(xs)
+        .rev()
+        .fold(Polynomial::zero(), |acc, (c, x)| {
+            let factor = Polynomial::new(vec![-x.clone(), T::one()]);
+            &(&acc * &factor) + &Polynomial::constant(c.clone())
+        })
+}
+
+// The Taylor coefficients p(x), p'(x), p''(x)/2!, ..., p^(k)(x)/k! of the
+// Newton form with coefficients `ddiffs` and nodes `xs`.
+//
+// This is Horner's rule on truncated power series in (X - x): the nested form
+// c_0 + (X - x_0)(c_1 + (X - x_1)(...)) is evaluated from the inside out,
+// writing each factor as (X - x) + (x - x_j).
+pub(crate) fn newton_taylor<'a, T, I>(ddiffs: &[T], xs: I, x: &T, k: usize) -> Vec<T>
+where
+    T: Scalar + 'a,
+    I: DoubleEndedIterator<Item = &'a T> + ExactSizeIterator,
+{
+    let mut q = vec![T::zero(); k + 1];
+    for (c, xj) in ddiffs.iter().zip(xs).rev() {
+        let d = x.clone() - xj.clone();
+        for i in (1..=k).rev() {
+            q[i] = q[i].clone() * d.clone() + q[i - 1].clone();
+        }
+        q[0] = c.clone() + q[0].clone() * d;
+    }
+    q
+}
+
+// The value and the first k derivatives at `x` of the Newton form, from its
+// Taylor coefficients p^(i)(x) / i!.
+pub(crate) fn newton_derivatives<'a, T, I>(ddiffs: &[T], xs: I, x: &T, k: usize) -> Vec<T>
+where
+    T: Scalar + 'a,
+    I: DoubleEndedIterator<Item = &'a T> + ExactSizeIterator,
+{
+    let mut derivs = newton_taylor(ddiffs, xs, x, k);
+    let mut factorial = T::one();
+    for (i, d) in derivs.iter_mut().enumerate().skip(1) {
+        factorial = factorial * T::from_i64(i as i64);
+        *d = d.clone() * factorial.clone();
+    }
+    derivs
+}
+
+/// An iterator over the columns of a divided difference table.
+///
+/// The k-th item is the column of order k, whose i-th entry is
+/// `f[x_i, ..., x_{i+k}]`; its length is `n - k` for `n` points. The first
+/// entries of the columns are the coefficients of the Newton form. Each
+/// column is computed from the previous one in O(n) time and memory, so the
+/// whole table can be inspected without ever being held in memory at once.
+#[derive(Debug, Clone)]
+pub struct DividedDifferences<'a, T = f32> {
+    points : &'a [Point<T>],
+    column : Vec<T>,
+    order : usize
+}
+
+impl<T: Scalar> Iterator for DividedDifferences<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.column.is_empty() {
+            return None;
+        }
+        let k = self.order + 1;
+        let next = self.column
+            .windows(2)
+            .zip(self.points.iter().zip(&self.points[k.min(self.points.len())..]))
+            .map(|(c, (pi, pk))| (c[1].clone() - c[0].clone()) / (pk.x.clone() - pi.x.clone()))
+            .collect();
+        self.order = k;
+        Some(std::mem::replace(&mut self.column, next))
+    }
+}
+
+impl<T> NewtonPolynomial<T> {
+    /// The interpolation nodes, in the order used by the Newton basis.
+    pub fn points(&self) -> &[Point<T>] {
+        &self.points
+    }
+
+    /// The Newton coefficients `f[x_0], f[x_0, x_1], ..., f[x_0, ..., x_n]`.
+    pub fn ddiffs(&self) -> &[T] {
+        &self.ddiffs
+    }
+}
+
+impl<T: Scalar> Default for NewtonPolynomial<T> {
+    fn default() -> Self {
+        NewtonPolynomial::new()
+    }
+}
+
+impl<T: Scalar> PolyInterpolate<T> for NewtonPolynomial<T> {
+    /// Adds the points one at a time, in O(n^2) overall.
+    fn interpolate(points: &[Point<T>]) -> Self {
+        let mut np = NewtonPolynomial::new();
+        for p in points {
+            np.add_point(p.clone());
+        }
+        np
+    }
+}
+
+impl<T: Scalar> PolyGetPoints<T> for NewtonPolynomial<T> {
+
+    fn get_y(&self, x: &T) -> T {
+        if let Some(point) = self.points
+            .iter()
+            .find(|p| p.x == *x) {
+                point.y.clone()
+        } else if self.ddiffs.is_empty() {
+            T::zero()
+        } else {
+            self.ddiffs[1..].iter()
+                .zip(self.points.iter()
+                        .map(|p| x.clone() - p.x.clone())
+                        .scan(T::one(), |acc, x| {
+                            *acc = acc.clone() * x;
+                            Some(acc.clone())
+                        }) // Newton basis polynomials
+                )
+                .fold(T::zero(), |acc, z| acc + z.0.clone() * z.1)
+            + self.ddiffs[0].clone() // first divided difference in the sum doesn't have a multiplier
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{book_points, q};
+    use crate::{Gf, Rational};
+
+    // the original recursive definition of f[x_i, ..., x_j], kept as an oracle
+    fn ddiff<T: Scalar>(i: usize, j: usize, points: &[Point<T>]) -> T {
+        let (pi, pj) = (&points[i], &points[j]);
+        match j - i {
+            0 => pi.y.clone(),
+            1 => (pj.y.clone() - pi.y.clone()) / (pj.x.clone() - pi.x.clone()),
+            _ => (ddiff(i + 1, j, points) - ddiff(i, j - 1, points)) / (pj.x.clone() - pi.x.clone()),
+        }
+    }
+
+    fn assert_matches_recursion<T: Scalar>(points: &[Point<T>]) {
+        let n = points.len();
+        let top: Vec<T> = (0..n).map(|j| ddiff(0, j, points)).collect();
+        assert_eq!(NewtonPolynomial::interpolate(points).ddiffs(), &top[..]);
+        let columns: Vec<Vec<T>> = NewtonPolynomial::ddiff_table(points).collect();
+        assert_eq!(columns.len(), n);
+        for (k, column) in columns.iter().enumerate() {
+            let expected: Vec<T> = (0..n - k).map(|i| ddiff(i, i + k, points)).collect();
+            assert_eq!(column, &expected, "column {}", k);
+        }
+    }
+
+    #[test]
+    fn ddiff_table_matches_recursion() {
+        let q = |n: i64, d: i64| Rational::new(n, d);
+        for n in 1..=6 {
+            let points: Vec<Point<Rational>> = (0..n)
+                .map(|i| Point::new(q(i * i + 1, i + 2), q(7 - 2 * i * i * i, 3)))
+                .collect();
+            assert_matches_recursion(&points);
+        }
+        let points: Vec<Point<Gf<7919>>> = [(2, 5), (37, 1), (81, 4000), (5, 7918), (100, 0), (3, 3)]
+            .iter()
+            .map(|&(x, y)| Point::new(Gf::new(x), Gf::new(y)))
+            .collect();
+        assert_matches_recursion(&points);
+    }
+
+    #[test]
+    fn ddiff_table_of_no_points_is_empty() {
+        assert_eq!(NewtonPolynomial::<Rational>::ddiff_table(&[]).count(), 0);
+    }
+
+    #[test]
+    fn to_monomial_recovers_the_polynomial() {
+        let (p, points) = book_points();
+        let np = NewtonPolynomial::interpolate(&points);
+        assert_eq!(np.to_monomial(), p);
+        for x in (10..100).map(Rational::from) {
+            assert_eq!(np.get_y(&x), p.get_y(&x));
+        }
+    }
+
+    #[test]
+    fn points_can_be_added_and_removed() {
+        let (p, points) = book_points();
+        let mut np = NewtonPolynomial::new();
+        for pt in &points {
+            np.add_point(pt.clone());
+        }
+        assert_eq!(np.to_monomial(), p);
+        let extra = Point::new(q("5"), q("1"));
+        np.add_point(extra.clone());
+        assert_eq!(np.remove_point(3), extra);
+        assert_eq!(np.to_monomial(), p);
+        // the bottom diagonal is restored, so appending still works
+        np.add_point(extra.clone());
+        let all: Vec<Point<Rational>> = points.iter().cloned().chain([extra]).collect();
+        assert_eq!(np.ddiffs(), NewtonPolynomial::interpolate(&all).ddiffs());
+        assert_eq!(np.remove_point(0), points[0]);
+        assert_eq!(np.to_monomial(), NewtonPolynomial::interpolate(&all[1..]).to_monomial());
+    }
+
+    #[test]
+    fn derivatives_and_integrals_match_the_polynomial() {
+        let (p, points) = book_points();
+        let np = NewtonPolynomial::interpolate(&points);
+        let x = q("50");
+        let expected: Vec<Rational> = (0..=3).map(|k| p.derivative(k).horner(&x)).collect();
+        assert_eq!(np.derivatives(&x, 3), expected);
+        assert_eq!(np.integrate(&q("0"), &q("1")), p.integrate(&q("0"), &q("1")));
+    }
+}
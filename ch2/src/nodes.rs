@@ -0,0 +1,248 @@
+use std::f64::consts::PI;
+use std::fmt;
+
+use crate::Real;
+
+// Families of interpolation nodes on an interval [a, b]. The choice matters:
+// equispaced nodes make high degree interpolation diverge for smooth functions
+// such as Runge's 1/(1 + 25x^2), while nodes that cluster towards the ends of
+// the interval like the Chebyshev and Legendre points do not.
+// See Trefethen, "Approximation Theory and Approximation Practice", Ch. 5 and 15.
+
+// the affine map from [-1, 1] onto [a, b], clamped so that rounding cannot
+// move the ends outside the interval
+fn to_interval<T: Real>(t: f64, a: f64, b: f64) -> T {
+    let x = 0.5 * (a + b) + 0.5 * (b - a) * t;
+    T::from_f64(x.max(a.min(b)).min(a.max(b)))
+}
+
+/// `n` equally spaced nodes including both ends of `[a, b]`, in increasing
+/// order. A single node is placed at the midpoint.
+pub fn equispaced<T: Real>(n: usize, a: f64, b: f64) -> Vec<T> {
+    if n == 1 {
+        return vec![to_interval(0.0, a, b)];
+    }
+    (0..n)
+        .map(|i| to_interval(-1.0 + 2.0 * i as f64 / (n - 1) as f64, a, b))
+        .collect()
+}
+
+/// The `n` Chebyshev points of the first kind, the roots of `T_n`, mapped to
+/// `[a, b]` in increasing order. They do not include the ends.
+pub fn chebyshev_first<T: Real>(n: usize, a: f64, b: f64) -> Vec<T> {
+    (0..n)
+        .rev()
+        .map(|k| to_interval((PI * (2 * k + 1) as f64 / (2 * n) as f64).cos(), a, b))
+        .collect()
+}
+
+/// The `n` Chebyshev points of the second kind, the extrema of `T_{n-1}`,
+/// mapped to `[a, b]` in increasing order. They include both ends.
+pub fn chebyshev_second<T: Real>(n: usize, a: f64, b: f64) -> Vec<T> {
+    if n == 1 {
+        return vec![to_interval(0.0, a, b)];
+    }
+    (0..n)
+        .rev()
+        .map(|k| to_interval((PI * k as f64 / (n - 1) as f64).cos(), a, b))
+        .collect()
+}
+
+/// The nodes and weights of the `n` point Gauss-Legendre quadrature rule on
+/// `[-1, 1]`, in increasing order of the nodes.
+///
+/// The nodes are the roots of the Legendre polynomial `P_n`, found by Newton's
+/// method from the asymptotic initial guesses `cos(pi (i + 3/4) / (n + 1/2))`;
+/// the weights are `2 / ((1 - x^2) P_n'(x)^2)`.
+pub fn gauss_legendre_rule(n: usize) -> Vec<(f64, f64)> {
+    let mut rule = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut x = (PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+        let mut dp = 0.0;
+        for _ in 0..100 {
+            // P_n(x) and P_n'(x) by the three term recurrence
+            let (mut p0, mut p1) = (1.0, x);
+            for k in 2..=n {
+                let k = k as f64;
+                (p0, p1) = (p1, ((2.0 * k - 1.0) * x * p1 - (k - 1.0) * p0) / k);
+            }
+            let p = if n == 0 { 1.0 } else { p1 };
+            dp = n as f64 * (x * p - p0) / (x * x - 1.0);
+            let step = p / dp;
+            x -= step;
+            if step.abs() <= 4.0 * f64::EPSILON {
+                break;
+            }
+        }
+        rule.push((x, 2.0 / ((1.0 - x * x) * dp * dp)));
+    }
+    rule.reverse();
+    rule
+}
+
+/// The `n` Gauss-Legendre points, the roots of `P_n`, mapped to `[a, b]` in
+/// increasing order.
+pub fn legendre_gauss<T: Real>(n: usize, a: f64, b: f64) -> Vec<T> {
+    gauss_legendre_rule(n)
+        .into_iter()
+        .map(|(x, _)| to_interval(x, a, b))
+        .collect()
+}
+
+/// Reorders `nodes` into a Leja sequence: the node of largest magnitude
+/// first, then repeatedly the node maximizing the product of distances to the
+/// nodes already chosen.
+///
+/// Every prefix of a Leja sequence is itself a good node set, which makes it
+/// the natural order for the Newton form and for adding nodes incrementally.
+/// Products are compared through sums of logarithms to avoid overflow.
+pub fn leja_order<T: Real>(nodes: &[T]) -> Vec<T> {
+    let xs: Vec<f64> = nodes.iter().map(|x| x.to_f64()).collect();
+    leja_indices(&xs, xs.len()).into_iter().map(|i| nodes[i]).collect()
+}
+
+// the indices of the first n nodes of the Leja sequence of xs, in O(n |xs|)
+fn leja_indices(xs: &[f64], n: usize) -> Vec<usize> {
+    let n = n.min(xs.len());
+    let mut chosen: Vec<usize> = Vec::with_capacity(n);
+    // score[i] is the log of the product of distances to the chosen nodes
+    let mut score: Vec<f64> = vec![0.0; xs.len()];
+    let mut left: Vec<usize> = (0..xs.len()).collect();
+    while chosen.len() < n {
+        let pos = if chosen.is_empty() {
+            (0..left.len()).max_by(|&i, &j| xs[left[i]].abs().total_cmp(&xs[left[j]].abs()))
+        } else {
+            (0..left.len()).max_by(|&i, &j| score[left[i]].total_cmp(&score[left[j]]))
+        }
+        .unwrap();
+        let next = left.swap_remove(pos);
+        for &i in &left {
+            score[i] += (xs[i] - xs[next]).abs().ln();
+        }
+        chosen.push(next);
+    }
+    chosen
+}
+
+/// The first `n` Leja points of `[a, b]`, chosen greedily from a fine grid of
+/// Chebyshev points of the second kind. They are returned in Leja order,
+/// not in increasing order.
+pub fn leja<T: Real>(n: usize, a: f64, b: f64) -> Vec<T> {
+    let candidates: Vec<f64> = chebyshev_second(64 * n.max(1) + 1, a, b);
+    leja_indices(&candidates, n)
+        .into_iter()
+        .map(|i| T::from_f64(candidates[i]))
+        .collect()
+}
+
+/// The node families of this module, for comparing them side by side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeFamily {
+    Equispaced,
+    ChebyshevFirst,
+    ChebyshevSecond,
+    LegendreGauss,
+    Leja,
+}
+
+impl NodeFamily {
+    pub const ALL: [NodeFamily; 5] = [
+        NodeFamily::Equispaced,
+        NodeFamily::ChebyshevFirst,
+        NodeFamily::ChebyshevSecond,
+        NodeFamily::LegendreGauss,
+        NodeFamily::Leja,
+    ];
+
+    /// `n` nodes of this family on `[a, b]`.
+    pub fn nodes<T: Real>(self, n: usize, a: f64, b: f64) -> Vec<T> {
+        match self {
+            NodeFamily::Equispaced => equispaced(n, a, b),
+            NodeFamily::ChebyshevFirst => chebyshev_first(n, a, b),
+            NodeFamily::ChebyshevSecond => chebyshev_second(n, a, b),
+            NodeFamily::LegendreGauss => legendre_gauss(n, a, b),
+            NodeFamily::Leja => leja(n, a, b),
+        }
+    }
+}
+
+impl fmt::Display for NodeFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            NodeFamily::Equispaced => "equispaced",
+            NodeFamily::ChebyshevFirst => "chebyshev1",
+            NodeFamily::ChebyshevSecond => "chebyshev2",
+            NodeFamily::LegendreGauss => "legendre",
+            NodeFamily::Leja => "leja",
+        };
+        f.pad(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn families_give_n_increasing_nodes_in_the_interval() {
+        for family in NodeFamily::ALL {
+            for (a, b) in [(-1.0, 1.0), (0.1, 0.7), (-3.0, 250.0)] {
+                for n in [0, 1, 2, 3, 10, 33] {
+                    let mut xs: Vec<f64> = family.nodes(n, a, b);
+                    assert_eq!(xs.len(), n, "{} {}", family, n);
+                    if family == NodeFamily::Leja {
+                        xs.sort_by(f64::total_cmp);
+                    }
+                    assert!(xs.windows(2).all(|w| w[0] < w[1]), "{} {}: {:?}", family, n, xs);
+                    assert!(xs.iter().all(|x| (a..=b).contains(x)), "{} {}: {:?}", family, n, xs);
+                }
+                let mid = 0.5 * (a + b);
+                if family != NodeFamily::Leja {
+                    assert!((family.nodes::<f64>(1, a, b)[0] - mid).abs() <= 1e-15 * (b - a), "{}", family);
+                }
+            }
+        }
+        assert_eq!(equispaced::<f64>(5, 0.0, 1.0), [0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert!(chebyshev_second::<f64>(3, -1.0, 1.0)[1].abs() < 1e-15);
+    }
+
+    #[test]
+    fn gauss_legendre_rule_matches_the_tables() {
+        for n in 1..=40 {
+            let rule = gauss_legendre_rule(n);
+            let total: f64 = rule.iter().map(|(_, w)| w).sum();
+            assert!((total - 2.0).abs() < 1e-13, "{}: {}", n, total);
+            // exact for polynomials of degree 2n - 1
+            let moment: f64 = rule.iter().map(|(x, w)| w * x.powi(2 * n as i32 - 2)).sum();
+            assert!((moment - 2.0 / (2 * n - 1) as f64).abs() < 1e-13, "{}: {}", n, moment);
+        }
+        let expected = [
+            (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+            (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+            (0.0, 128.0 / 225.0),
+            (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+            (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+        ];
+        for ((x, w), (ex, ew)) in gauss_legendre_rule(5).into_iter().zip(expected) {
+            assert!((x - ex).abs() < 1e-15 && (w - ew).abs() < 1e-15, "{} {}", x, w);
+        }
+        assert!(gauss_legendre_rule(0).is_empty());
+        assert_eq!(gauss_legendre_rule(1), [(0.0, 2.0)]);
+    }
+
+    #[test]
+    fn leja_sequences_extend_their_prefixes() {
+        let grid: Vec<f64> = chebyshev_second(129, -1.0, 1.0);
+        let full = leja_order(&grid);
+        assert_eq!(full.len(), grid.len());
+        for n in [0, 1, 2, 7, 40] {
+            let prefix: Vec<f64> = leja_indices(&grid, n).into_iter().map(|i| grid[i]).collect();
+            assert_eq!(prefix, full[..n]);
+        }
+        // the largest magnitude first, then the opposite end, then the middle
+        let xs = leja::<f64>(3, 0.0, 4.0);
+        assert_eq!(xs[0], 4.0);
+        assert!(xs[1].abs() < 1e-15);
+        assert!((xs[2] - 2.0).abs() < 1e-12);
+    }
+}
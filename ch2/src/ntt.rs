@@ -0,0 +1,193 @@
+use crate::scalar::schoolbook_mul;
+use crate::Gf;
+
+// The number theoretic transform is the discrete Fourier transform over GF(P),
+// with a primitive n-th root of unity in the field in place of e^{-2 pi i / n}.
+// Such a root exists exactly when n divides P - 1, so radix-2 transforms need
+// a prime with a large power of two in P - 1, e.g. 998244353 = 119 * 2^23 + 1.
+// All arithmetic is exact, so products of polynomials over GF(P) are computed
+// in O(n log n) without any rounding.
+// See https://en.wikipedia.org/wiki/Discrete_Fourier_transform_over_a_ring
+
+/// Above this many coefficients in both factors, multiplication of
+/// polynomials over `GF(P)` switches from the schoolbook method to the NTT,
+/// provided that `P` supports transforms of the length needed.
+pub const NTT_THRESHOLD: usize = 32;
+
+/// The NTT-friendly prime `119 * 2^23 + 1`, which supports transforms of
+/// length up to `2^23`.
+pub const NTT_PRIME: u64 = 998_244_353;
+
+/// The largest power of two dividing `P - 1`, i.e. the longest radix-2
+/// transform over `GF(P)`.
+pub fn max_len<const P: u64>() -> usize {
+    1 << (P - 1).trailing_zeros().min(usize::BITS - 1)
+}
+
+/// A primitive `n`-th root of unity in `GF(P)`, for a power of two `n` that
+/// divides `P - 1`, or `None` if there is none.
+///
+/// The root is derived from the smallest quadratic non-residue `g`: by
+/// Euler's criterion `g^((P - 1) / 2) = -1`, so `g^((P - 1) / 2^s)` has order
+/// exactly `2^s` for the largest such `s`. This needs no factorization of
+/// `P - 1`.
+pub fn root_of_unity<const P: u64>(n: usize) -> Option<Gf<P>> {
+    if !n.is_power_of_two() || n > max_len::<P>() {
+        return None;
+    }
+    if n == 1 {
+        return Some(Gf::new(1));
+    }
+    let minus_one = Gf::new(P - 1);
+    // a non-residue exists for every odd prime; for a composite P the search
+    // may fail, and gives up after a while
+    let g = (2..P.min(1 << 16)).map(Gf::<P>::new).find(|g| g.pow((P - 1) / 2) == minus_one)?;
+    let max = max_len::<P>() as u64;
+    Some(g.pow((P - 1) / max).pow(max / n as u64))
+}
+
+// in-place iterative Cooley-Tukey with the primitive n-th root of unity w
+fn transform<const P: u64>(data: &mut [Gf<P>], w: Gf<P>) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        // w^(n / len) is a primitive len-th root of unity; its powers are
+        // exact, so they can be accumulated
+        let step = w.pow((n / len) as u64);
+        let twiddles: Vec<Gf<P>> = std::iter::successors(Some(Gf::new(1)), |t| Some(*t * step))
+            .take(len / 2)
+            .collect();
+        for chunk in data.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((u, v), w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let t = *v * *w;
+                (*u, *v) = (*u + t, *u - t);
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// The transform `X_k = sum_j x_j w^(jk)` with `w` the primitive root of
+/// unity from [`root_of_unity`], i.e. the values of the polynomial with
+/// coefficients `x_j` at the powers of `w`. `None` if the length is not a
+/// power of two dividing `P - 1`.
+pub fn ntt<const P: u64>(data: &[Gf<P>]) -> Option<Vec<Gf<P>>> {
+    let w = root_of_unity::<P>(data.len().max(1))?;
+    let mut out = data.to_vec();
+    transform(&mut out, w);
+    Some(out)
+}
+
+/// The inverse of [`ntt`], `x_j = 1/n sum_k X_k w^(-jk)`.
+pub fn intt<const P: u64>(data: &[Gf<P>]) -> Option<Vec<Gf<P>>> {
+    let n = data.len().max(1);
+    let w = root_of_unity::<P>(n)?;
+    let mut out = data.to_vec();
+    transform(&mut out, w.inv()?);
+    let scale = Gf::<P>::new(n as u64).inv()?;
+    Some(out.into_iter().map(|x| x * scale).collect())
+}
+
+/// The product of the polynomials with coefficients `a` and `b`, by
+/// pointwise multiplication of their transforms. `None` if `P - 1` has too
+/// small a power of two for the length of the product.
+pub fn multiply<const P: u64>(a: &[Gf<P>], b: &[Gf<P>]) -> Option<Vec<Gf<P>>> {
+    if a.is_empty() || b.is_empty() {
+        return Some(Vec::new());
+    }
+    let len = a.len() + b.len() - 1;
+    let m = len.next_power_of_two();
+    let pad = |x: &[Gf<P>]| {
+        let mut x = x.to_vec();
+        x.resize(m, Gf::new(0));
+        ntt(&x)
+    };
+    let product: Vec<Gf<P>> = pad(a)?.iter().zip(&pad(b)?).map(|(x, y)| *x * *y).collect();
+    let mut product = intt(&product)?;
+    product.truncate(len);
+    Some(product)
+}
+
+// Scalar::mul_coeffs for GF(P)
+pub(crate) fn mul_coeffs<const P: u64>(a: &[Gf<P>], b: &[Gf<P>]) -> Vec<Gf<P>> {
+    if a.len().min(b.len()) < NTT_THRESHOLD {
+        return schoolbook_mul(a, b);
+    }
+    multiply(a, b).unwrap_or_else(|| schoolbook_mul(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::{Rng, Xoshiro256};
+    use crate::Polynomial;
+
+    fn random<const P: u64>(rng: &mut Xoshiro256, n: usize) -> Vec<Gf<P>> {
+        (0..n).map(|_| Gf::new(rng.below(P))).collect()
+    }
+
+    fn assert_products_match<const P: u64>(a: &[Gf<P>], b: &[Gf<P>]) {
+        let expected = schoolbook_mul(a, b);
+        let product = &Polynomial::new(a.to_vec()) * &Polynomial::new(b.to_vec());
+        assert_eq!(product, Polynomial::new(expected), "lengths {} and {}", a.len(), b.len());
+    }
+
+    #[test]
+    fn roots_of_unity_have_exact_order() {
+        for n in [2, 4, 1 << 10, max_len::<NTT_PRIME>()] {
+            let w = root_of_unity::<NTT_PRIME>(n).unwrap();
+            assert_eq!(w.pow(n as u64), Gf::new(1));
+            assert_eq!(w.pow(n as u64 / 2), Gf::new(NTT_PRIME - 1));
+        }
+        assert_eq!(max_len::<NTT_PRIME>(), 1 << 23);
+        assert_eq!(root_of_unity::<NTT_PRIME>(1 << 24), None);
+        assert_eq!(root_of_unity::<NTT_PRIME>(3), None);
+        assert_eq!(root_of_unity::<7919>(4), None);
+    }
+
+    #[test]
+    fn ntt_is_the_dft_and_intt_inverts_it() {
+        let mut rng = Xoshiro256::new(25);
+        let x: Vec<Gf<NTT_PRIME>> = random(&mut rng, 16);
+        let w = root_of_unity::<NTT_PRIME>(16).unwrap();
+        let dft: Vec<Gf<NTT_PRIME>> = (0..16)
+            .map(|k| x.iter().enumerate().fold(Gf::new(0), |acc, (j, c)| acc + *c * w.pow((j * k) as u64)))
+            .collect();
+        assert_eq!(ntt(&x), Some(dft.clone()));
+        assert_eq!(intt(&dft), Some(x));
+        assert_eq!(ntt(&random::<NTT_PRIME>(&mut rng, 12)), None);
+    }
+
+    #[test]
+    fn multiply_matches_schoolbook() {
+        let mut rng = Xoshiro256::new(24);
+        let t = NTT_THRESHOLD;
+        let lengths = [(t - 1, t - 1), (t - 1, 200), (t, t), (t + 1, t), (t + 1, t + 1), (37, 50), (100, 3), (1, 200), (127, 129), (300, 301)];
+        for (m, n) in lengths {
+            let (a, b) = (random::<NTT_PRIME>(&mut rng, m), random(&mut rng, n));
+            assert_eq!(multiply(&a, &b), Some(schoolbook_mul(&a, &b)), "lengths {} and {}", m, n);
+            assert_products_match(&a, &b);
+        }
+        assert_eq!(multiply::<NTT_PRIME>(&[], &[Gf::new(1)]), Some(Vec::new()));
+    }
+
+    #[test]
+    fn other_primes_fall_back_to_schoolbook() {
+        // 7918 = 2 * 3959 only allows transforms of length 2
+        let mut rng = Xoshiro256::new(7919);
+        let (a, b) = (random::<7919>(&mut rng, 2 * NTT_THRESHOLD), random(&mut rng, 3 * NTT_THRESHOLD + 1));
+        assert_eq!(multiply(&a, &b), None);
+        assert_products_match(&a, &b);
+    }
+}
@@ -0,0 +1,22 @@
+/// A point `(x, y)` in the plane, used both as interpolation data and as the
+/// result of evaluating a polynomial.
+///
+/// The coordinates default to `f32`; any [`Scalar`](crate::Scalar) may be
+/// used instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point<T = f32> {
+    pub x : T,
+    pub y : T
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
@@ -0,0 +1,353 @@
+use std::fmt;
+
+use crate::rng::{Rng, SplitMix64, Xoshiro256};
+use crate::{Gf, HermitePolynomial, LagrangePolynomial, NewtonPolynomial, Point, PolyGetPoints, PolyInterpolate, Polynomial, Rational, Real, Scalar, Validation};
+
+// A small property-based testing harness for interpolation invariants.
+// Random point sets are drawn from a seeded generator and checked against a
+// property; the first failing set is shrunk to a minimal one by dropping
+// points and simplifying coordinates, and printed as Rust source that can be
+// pasted into a regression test.
+
+/// Scalars that can be simplified when shrinking a failing case and printed
+/// as a Rust expression.
+pub trait Shrink: Scalar {
+    /// Strictly simpler candidates to replace `self` with, simplest first.
+    fn shrink(&self) -> Vec<Self>;
+
+    /// A Rust expression evaluating to `self`.
+    fn literal(&self) -> String;
+}
+
+macro_rules! impl_shrink_float {
+    ($t:ty) => {
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let x = *self;
+                let mut out: Vec<Self> = vec![0.0, x.trunc(), (x * 10.0).round() / 10.0, (x * 100.0).round() / 100.0];
+                if x < 0.0 {
+                    out.push(-x);
+                }
+                out.dedup();
+                out.retain(|c| *c != x);
+                out
+            }
+
+            fn literal(&self) -> String {
+                format!("{:?}", self)
+            }
+        }
+    };
+}
+
+impl_shrink_float!(f32);
+impl_shrink_float!(f64);
+
+impl Shrink for Rational {
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = vec![Rational::zero(), Rational::from(self.numer() / self.denom())];
+        if self.numer().is_negative() {
+            out.push(-self.clone());
+        }
+        out.dedup();
+        out.retain(|c| c != self);
+        out
+    }
+
+    fn literal(&self) -> String {
+        format!("\"{}\".parse::<Rational>().unwrap()", self)
+    }
+}
+
+impl<const P: u64> Shrink for Gf<P> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = vec![Gf::new(0), Gf::new(1), Gf::new(self.value() / 2)];
+        out.dedup();
+        out.retain(|c| c.value() < self.value());
+        out
+    }
+
+    fn literal(&self) -> String {
+        format!("Gf::new({})", self.value())
+    }
+}
+
+/// How many cases to try and how hard to shrink.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub cases : usize,
+    pub seed : u64,
+    /// Upper bound on the number of property evaluations spent shrinking.
+    pub max_shrinks : usize
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { cases: 100, seed: 0x5eed, max_shrinks: 1000 }
+    }
+}
+
+/// A minimal counterexample to a property.
+#[derive(Debug, Clone)]
+pub struct Failure<T> {
+    pub property : &'static str,
+    /// The configuration and case number that produced the counterexample,
+    /// so that the run can be replayed.
+    pub seed : u64,
+    pub case : usize,
+    pub message : String,
+    /// The shrunk points.
+    pub points : Vec<Point<T>>
+}
+
+impl<T: Shrink> fmt::Display for Failure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "property `{}` failed on case {} of seed {:#x}: {}", self.property, self.case, self.seed, self.message)?;
+        write!(f, "minimal failing input:\nvec![")?;
+        for (i, p) in self.points.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "Point::new({}, {})", p.x.literal(), p.y.literal())?;
+        }
+        write!(f, "]")
+    }
+}
+
+// the seed of every case is derived from the run seed, so cases are independent
+fn case_seed(seed: u64, case: usize) -> u64 {
+    let mut sm = SplitMix64::new(seed ^ (case as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+    sm.next_u64()
+}
+
+/// Checks `property` on `config.cases` point sets drawn by `generate`.
+///
+/// Generated sets that are not valid interpolation input (empty, repeated or
+/// non-finite nodes) are skipped. On failure, the counterexample is shrunk
+/// with [`shrink`].
+pub fn check<T, G, P>(config: &Config, name: &'static str, generate: G, property: P) -> Result<(), Failure<T>>
+where
+    T: Shrink,
+    G: Fn(&mut Xoshiro256) -> Vec<Point<T>>,
+    P: Fn(&[Point<T>]) -> Result<(), String>,
+{
+    for case in 0..config.cases {
+        let points = generate(&mut Xoshiro256::new(case_seed(config.seed, case)));
+        if !is_valid(&points) {
+            continue;
+        }
+        if property(&points).is_err() {
+            let points = shrink(points, &property, config.max_shrinks);
+            let message = property(&points).err().unwrap_or_default();
+            return Err(Failure { property: name, seed: config.seed, case, message, points });
+        }
+    }
+    Ok(())
+}
+
+fn is_valid<T: Scalar>(points: &[Point<T>]) -> bool {
+    Validation { min_relative_gap: 0.0 }.check(points).is_ok()
+}
+
+/// Greedily shrinks a failing point set: first by dropping single points,
+/// then by replacing single coordinates with simpler values, as long as the
+/// property keeps failing and the points stay valid interpolation input.
+/// At most `max_steps` candidates are tried.
+pub fn shrink<T, P>(mut points: Vec<Point<T>>, property: &P, max_steps: usize) -> Vec<Point<T>>
+where
+    T: Shrink,
+    P: Fn(&[Point<T>]) -> Result<(), String>,
+{
+    let mut steps = 0;
+    while steps < max_steps {
+        let budget = max_steps - steps;
+        let Some(smaller) = candidates(&points)
+            .take(budget)
+            .find(|c| {
+                steps += 1;
+                is_valid(c) && property(c).is_err()
+            }) else {
+            break;
+        };
+        points = smaller;
+    }
+    points
+}
+
+// all point sets one shrinking step away from `points`, most promising first
+fn candidates<T: Shrink>(points: &[Point<T>]) -> impl Iterator<Item = Vec<Point<T>>> + '_ {
+    let removals = (0..points.len()).map(move |i| {
+        let mut c = points.to_vec();
+        c.remove(i);
+        c
+    });
+    let simplifications = (0..points.len()).flat_map(move |i| {
+        let xs = points[i].x.shrink().into_iter().map(move |x| {
+            let mut c = points.to_vec();
+            c[i].x = x;
+            c
+        });
+        let ys = points[i].y.shrink().into_iter().map(move |y| {
+            let mut c = points.to_vec();
+            c[i].y = y;
+            c
+        });
+        xs.chain(ys)
+    });
+    removals.chain(simplifications)
+}
+
+/// Exact equality, for exact scalars.
+pub fn exact_eq<T: Scalar>(a: &T, b: &T) -> bool {
+    a == b
+}
+
+/// Equality up to the relative tolerance `tol`, for floating point scalars.
+pub fn approx_eq<T: Real>(tol: f64) -> impl Fn(&T, &T) -> bool {
+    move |a, b| {
+        let (a, b) = (a.to_f64(), b.to_f64());
+        (a - b).abs() <= tol * 1_f64.max(a.abs()).max(b.abs())
+    }
+}
+
+// points between consecutive nodes, where interpolants can be compared
+fn test_xs<T: Scalar>(points: &[Point<T>]) -> Vec<T> {
+    if points.len() == 1 {
+        return vec![points[0].x.clone() + T::one()];
+    }
+    let two = T::from_i64(2);
+    points.windows(2)
+        .map(|w| (w[0].x.clone() + w[1].x.clone()) / two.clone())
+        .collect()
+}
+
+fn compare<T: Scalar>(what: &str, xs: &[impl fmt::Debug], a: &[T], b: &[T], eq: &impl Fn(&T, &T) -> bool) -> Result<(), String> {
+    match (0..a.len().max(b.len())).find(|&i| {
+        let (ai, bi) = (a.get(i).cloned().unwrap_or_else(T::zero), b.get(i).cloned().unwrap_or_else(T::zero));
+        !eq(&ai, &bi)
+    }) {
+        Some(i) => Err(format!("{} differ at {:?}: {:?} != {:?}", what, xs.get(i), a.get(i), b.get(i))),
+        None => Ok(()),
+    }
+}
+
+/// The interpolant `I` takes the value `y` at every node `x`.
+///
+/// The interpolants return the stored `y` when evaluated exactly at a node,
+/// so the values are taken from the monomial form produced by `expand`
+/// instead, which only depends on the interpolation formula.
+pub fn passes_through_nodes<T, I>(points: &[Point<T>], expand: impl Fn(&I) -> Polynomial<T>, eq: impl Fn(&T, &T) -> bool) -> Result<(), String>
+where
+    T: Scalar,
+    I: PolyInterpolate<T>,
+{
+    let p = expand(&I::interpolate(points));
+    match points.iter().find(|pt| !eq(&p.horner(&pt.x), &pt.y)) {
+        Some(pt) => Err(format!("interpolant misses the node {:?}", pt)),
+        None => Ok(()),
+    }
+}
+
+/// The Lagrange and Newton forms of the interpolant agree between the nodes.
+pub fn lagrange_newton_agree<T: Scalar>(points: &[Point<T>], eq: impl Fn(&T, &T) -> bool) -> Result<(), String> {
+    let xs = test_xs(points);
+    let lp = LagrangePolynomial::interpolate(points);
+    let np = NewtonPolynomial::interpolate(points);
+    let ly: Vec<T> = xs.iter().map(|x| lp.get_y(x)).collect();
+    let ny: Vec<T> = xs.iter().map(|x| np.get_y(x)).collect();
+    compare("Lagrange and Newton values", &xs, &ly, &ny, &eq)
+}
+
+/// Interpolating `n` samples of a polynomial of degree below `n` gives the
+/// polynomial back. The y coordinates of `points` are used as the
+/// coefficients of the polynomial and the x coordinates as the nodes, so that
+/// a counterexample is still just a list of points.
+pub fn reproduces_polynomial<T: Scalar>(points: &[Point<T>], eq: impl Fn(&T, &T) -> bool) -> Result<(), String> {
+    let p = Polynomial::new(points.iter().map(|p| p.y.clone()).collect::<Vec<T>>());
+    let xs: Vec<T> = points.iter().map(|p| p.x.clone()).collect();
+    let samples = p.get_points(&xs);
+    let lp = LagrangePolynomial::interpolate(&samples).to_monomial();
+    let np = NewtonPolynomial::interpolate(&samples).to_monomial();
+    let degrees: Vec<usize> = (0..points.len()).collect();
+    compare("Lagrange and original coefficients", &degrees, lp.coeffs(), p.coeffs(), &eq)?;
+    compare("Newton and original coefficients", &degrees, np.coeffs(), p.coeffs(), &eq)
+}
+
+/// The interpolant does not depend on the order of the nodes.
+pub fn order_invariant<T: Scalar>(points: &[Point<T>], eq: impl Fn(&T, &T) -> bool) -> Result<(), String> {
+    let xs = test_xs(points);
+    let reversed: Vec<Point<T>> = points.iter().rev().cloned().collect();
+    let values = |points: &[Point<T>]| -> (Vec<T>, Vec<T>) {
+        let lp = LagrangePolynomial::interpolate(points);
+        let np = NewtonPolynomial::interpolate(points);
+        (xs.iter().map(|x| lp.get_y(x)).collect(), xs.iter().map(|x| np.get_y(x)).collect())
+    };
+    let (l, n) = values(points);
+    let (lr, nr) = values(&reversed);
+    compare("Lagrange values for reversed nodes", &xs, &l, &lr, &eq)?;
+    compare("Newton values for reversed nodes", &xs, &n, &nr, &eq)
+}
+
+/// Checks all interpolation invariants of this module on point sets drawn by
+/// `generate`, comparing values with `eq`.
+pub fn check_interpolation_invariants<T, G, E>(config: &Config, generate: G, eq: E) -> Result<(), Failure<T>>
+where
+    T: Shrink,
+    G: Fn(&mut Xoshiro256) -> Vec<Point<T>>,
+    E: Fn(&T, &T) -> bool,
+{
+    check(config, "passes_through_nodes (Lagrange)", &generate, |p| passes_through_nodes(p, LagrangePolynomial::to_monomial, &eq))?;
+    check(config, "passes_through_nodes (Newton)", &generate, |p| passes_through_nodes(p, NewtonPolynomial::to_monomial, &eq))?;
+    check(config, "passes_through_nodes (Hermite)", &generate, |p| passes_through_nodes(p, HermitePolynomial::to_monomial, &eq))?;
+    check(config, "lagrange_newton_agree", &generate, |p| lagrange_newton_agree(p, &eq))?;
+    check(config, "reproduces_polynomial", &generate, |p| reproduces_polynomial(p, &eq))?;
+    check(config, "order_invariant", &generate, |p| order_invariant(p, &eq))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_ok<T: Shrink>(result: Result<(), Failure<T>>) {
+        if let Err(failure) = result {
+            panic!("{}", failure);
+        }
+    }
+
+    #[test]
+    fn invariants_hold_over_f64() {
+        assert_ok(check_interpolation_invariants(&Config::default(), |rng: &mut Xoshiro256| {
+            let n = 1 + rng.below(8) as usize;
+            let xs: Vec<f64> = crate::rng::random_nodes(rng, n, -1.0, 1.0);
+            xs.into_iter().map(|x| Point::new(x, rng.uniform(-1.0, 1.0))).collect()
+        }, approx_eq(1e-6)));
+    }
+
+    #[test]
+    fn invariants_hold_over_rationals() {
+        assert_ok(check_interpolation_invariants(&Config::default(), |rng: &mut Xoshiro256| {
+            let mut q = || Rational::new(rng.below(41) as i64 - 20, 1 + rng.below(4) as i64);
+            (0..6).map(|_| Point::new(q(), q())).collect()
+        }, exact_eq));
+    }
+
+    #[test]
+    fn invariants_hold_over_a_prime_field() {
+        type F = Gf<7919>;
+        assert_ok(check_interpolation_invariants(&Config::default(), |rng: &mut Xoshiro256| {
+            let mut g = || F::new(rng.below(F::MODULUS));
+            (0..10).map(|_| Point::new(g(), g())).collect()
+        }, exact_eq));
+    }
+
+    #[test]
+    fn failures_are_shrunk() {
+        // a wrong interpolant, which only passes through the origin
+        let result = check(&Config::default(), "zero interpolant", |rng: &mut Xoshiro256| {
+            let mut g = || Gf::<7919>::new(rng.below(7919));
+            (0..5).map(|_| Point::new(g(), g())).collect()
+        }, |p| passes_through_nodes(p, |_: &LagrangePolynomial<Gf<7919>>| Polynomial::zero(), exact_eq));
+        let failure = result.expect_err("the zero polynomial cannot interpolate random points");
+        assert_eq!(failure.points, vec![Point::new(Gf::new(0), Gf::new(1))]);
+    }
+}
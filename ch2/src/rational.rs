@@ -0,0 +1,306 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use crate::bigint::{BigInt, ParseNumberError};
+use crate::Scalar;
+
+/// An exact rational number `num / den` of arbitrary precision.
+///
+/// Values are kept normalized: `den` is positive and `gcd(num, den) == 1`, so
+/// every rational has exactly one representation and derived equality is
+/// numeric equality. Zero is `0/1`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num : BigInt,
+    den : BigInt
+}
+
+impl Rational {
+    /// The fraction `num / den` in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    pub fn new(num: i64, den: i64) -> Self {
+        Rational::from_parts(BigInt::from(num), BigInt::from(den))
+    }
+
+    /// The fraction `num / den` in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    pub fn from_parts(num: BigInt, den: BigInt) -> Self {
+        assert!(!den.is_zero(), "zero denominator");
+        let g = num.gcd(&den);
+        let (mut num, mut den) = (&num / &g, &den / &g);
+        if den.is_negative() {
+            num = -num;
+            den = -den;
+        }
+        Rational { num, den }
+    }
+
+    pub fn numer(&self) -> &BigInt {
+        &self.num
+    }
+
+    /// The denominator, which is always positive.
+    pub fn denom(&self) -> &BigInt {
+        &self.den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den.is_one()
+    }
+
+    /// The exact value of a finite float, or `None` for infinities and NaN.
+    ///
+    /// Note that this is the value actually stored, so `0.1` does not become
+    /// `1/10`; parse a decimal string for that.
+    pub fn from_f64(x: f64) -> Option<Self> {
+        if !x.is_finite() {
+            return None;
+        }
+        let bits = x.to_bits();
+        let exp = ((bits >> 52) & 0x7ff) as i64;
+        let frac = bits & ((1 << 52) - 1);
+        // x = m * 2^e
+        let (m, e) = if exp == 0 { (frac, -1074) } else { (frac | (1 << 52), exp - 1075) };
+        let mut num = BigInt::from(m);
+        if bits >> 63 == 1 {
+            num = -num;
+        }
+        Some(if e >= 0 {
+            Rational::from_parts(num.shl(e as usize), BigInt::one())
+        } else {
+            Rational::from_parts(num, BigInt::one().shl((-e) as usize))
+        })
+    }
+
+    /// The nearest `f64`, up to rounding of the last bit.
+    pub fn to_f64(&self) -> f64 {
+        if self.num.is_zero() {
+            return 0.0;
+        }
+        // scale so that the integer quotient has about 64 significant bits
+        let k = 64 + self.den.bits() as i64 - self.num.bits() as i64;
+        let q = if k >= 0 {
+            &self.num.shl(k as usize) / &self.den
+        } else {
+            &self.num / &self.den.shl((-k) as usize)
+        };
+        // split the power of two so that neither factor overflows on its own
+        let half = (-k / 2) as i32;
+        q.to_f64() * 2_f64.powi(half) * 2_f64.powi(-k as i32 - half)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational { num: BigInt::from(n), den: BigInt::one() }
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(n: BigInt) -> Self {
+        Rational { num: n, den: BigInt::one() }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // denominators are positive, so cross-multiplying preserves order
+        (&self.num * &other.den).cmp(&(&other.num * &self.den))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl FromStr for Rational {
+    type Err = ParseNumberError;
+
+    /// Parses integers (`-3`), fractions (`22/7`) and decimals (`80.9`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((num, den)) = s.split_once('/') {
+            let den: BigInt = den.parse()?;
+            if den.is_zero() {
+                return Err(ParseNumberError);
+            }
+            return Ok(Rational::from_parts(num.parse()?, den));
+        }
+        if let Some((int, frac)) = s.split_once('.') {
+            if frac.is_empty() || frac.starts_with(['+', '-']) {
+                return Err(ParseNumberError);
+            }
+            let digits = format!("{}{}", int, frac);
+            let ten = BigInt::from(10_u64);
+            let den = (0..frac.len()).fold(BigInt::one(), |acc, _| &acc * &ten);
+            return Ok(Rational::from_parts(digits.parse()?, den));
+        }
+        Ok(Rational::from(s.parse::<BigInt>()?))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational { num: -self.num, den: self.den }
+    }
+}
+
+impl Add for &Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: &Rational) -> Rational {
+        Rational::from_parts(&(&self.num * &rhs.den) + &(&rhs.num * &self.den), &self.den * &rhs.den)
+    }
+}
+
+impl Sub for &Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: &Rational) -> Rational {
+        Rational::from_parts(&(&self.num * &rhs.den) - &(&rhs.num * &self.den), &self.den * &rhs.den)
+    }
+}
+
+impl Mul for &Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: &Rational) -> Rational {
+        Rational::from_parts(&self.num * &rhs.num, &self.den * &rhs.den)
+    }
+}
+
+impl Div for &Rational {
+    type Output = Rational;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: &Rational) -> Rational {
+        assert!(!rhs.num.is_zero(), "division by zero");
+        Rational::from_parts(&self.num * &rhs.den, &self.den * &rhs.num)
+    }
+}
+
+forward_binop!(impl[] Rational; Add add, Sub sub, Mul mul, Div div);
+
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Rational::from(0)
+    }
+
+    fn one() -> Self {
+        Rational::from(1)
+    }
+
+    fn from_i64(n: i64) -> Self {
+        Rational::from(n)
+    }
+
+    fn approx_f64(&self) -> Option<f64> {
+        Some(self.to_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::q;
+
+    #[test]
+    fn parses_integers_fractions_and_decimals() {
+        assert_eq!(q("-3"), Rational::from(-3));
+        assert_eq!(q("22/7"), Rational::new(22, 7));
+        assert_eq!(q("-6/4"), Rational::new(-3, 2));
+        assert_eq!(q("80.9"), Rational::new(809, 10));
+        assert_eq!(q("-0.25"), Rational::new(-1, 4));
+        for bad in ["", "1/0", "1.", "1.-5", "x", "1/2/3"] {
+            assert!(bad.parse::<Rational>().is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn parses_decimals_beyond_u64_precision() {
+        let x = q("0.12345678901234567890");
+        assert_eq!(x, q("1234567890123456789/10000000000000000000"));
+        assert_eq!(&x * &q("100000000000000000000"), q("12345678901234567890"));
+    }
+
+    #[test]
+    fn values_are_normalized() {
+        let x = Rational::new(6, -4);
+        assert_eq!(x, Rational::new(-3, 2));
+        assert_eq!(x.numer(), &BigInt::from(-3_i64));
+        assert_eq!(x.denom(), &BigInt::from(2_i64));
+        assert_eq!(Rational::new(0, -7), Rational::zero());
+        assert!(!Rational::new(0, -7).numer().is_negative());
+        assert_eq!(q("-6/-4").to_string(), "3/2");
+        assert_eq!(q("4/2").to_string(), "2");
+    }
+
+    #[test]
+    #[should_panic(expected = "zero denominator")]
+    fn zero_denominator_panics() {
+        Rational::new(1, 0);
+    }
+
+    #[test]
+    fn field_arithmetic() {
+        let (a, b) = (q("1/3"), q("-3/4"));
+        assert_eq!(&a + &b, q("-5/12"));
+        assert_eq!(&a - &b, q("13/12"));
+        assert_eq!(&a * &b, q("-1/4"));
+        assert_eq!(&a / &b, q("-4/9"));
+        assert_eq!(a.clone() / a.clone(), Rational::one());
+        assert_eq!(-b.clone(), q("3/4"));
+        // denominators beyond a machine word
+        let tiny = q("1/18446744073709551616");
+        assert_eq!(&(&tiny + &tiny) * &q("9223372036854775808"), Rational::one());
+    }
+
+    #[test]
+    fn ordering() {
+        let mut xs = ["1/2", "-2/3", "0", "1/3", "-1/2", "7/2"].map(q);
+        xs.sort();
+        assert_eq!(xs, ["-2/3", "-1/2", "0", "1/3", "1/2", "7/2"].map(q));
+        assert!(q("-1/3") > q("-1/2"));
+        assert!(q("100000000000000000001/100000000000000000000") > Rational::one());
+    }
+
+    #[test]
+    fn conversion_to_and_from_floats() {
+        let subnormal = f64::from_bits(1);
+        for x in [0.5, -0.1, 1e300, -f64::MAX, f64::MIN_POSITIVE, subnormal, 3.0 * subnormal, 123456.789] {
+            let r = Rational::from_f64(x).unwrap();
+            assert_eq!(r.to_f64(), x, "{:e}", x);
+        }
+        assert_eq!(Rational::from_f64(0.5), Some(q("1/2")));
+        assert_eq!(Rational::from_f64(-0.0), Some(Rational::zero()));
+        assert_eq!(Rational::from_f64(2_f64.powi(70)), Some(Rational::from(BigInt::one().shl(70))));
+        assert_eq!(Rational::from_f64(subnormal).unwrap().denom(), &BigInt::one().shl(1074));
+        assert_eq!(Rational::from_f64(f64::NAN), None);
+        assert_eq!(Rational::from_f64(f64::INFINITY), None);
+        assert_eq!(q("1/3").to_f64(), 1.0 / 3.0);
+        assert_eq!(q("-2/3").to_f64(), -2.0 / 3.0);
+    }
+}
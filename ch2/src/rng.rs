@@ -0,0 +1,206 @@
+// Minimal random number generation, so the crate has no external dependencies.
+// Everything is driven by an explicit seed, so that randomized experiments can
+// be replayed exactly.
+
+use crate::{Point, Polynomial, Real};
+
+/// A source of uniformly distributed 64-bit words.
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    /// A uniformly distributed integer in `0..bound`, by rejection sampling.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        // reject the top partial copy of 0..bound to avoid modulo bias
+        let zone = u64::MAX - (u64::MAX - bound + 1) % bound;
+        loop {
+            let r = self.next_u64();
+            if r <= zone {
+                return r % bound;
+            }
+        }
+    }
+
+    /// A uniformly distributed float in `[0, 1)` with 53 random bits.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+    }
+
+    /// A uniformly distributed float in `[lo, hi)`.
+    fn uniform(&mut self, lo: f64, hi: f64) -> f64 {
+        lo + (hi - lo) * self.next_f64()
+    }
+
+    /// A standard normally distributed float, by the Box-Muller transform.
+    fn normal(&mut self) -> f64 {
+        // 1 - u lies in (0, 1], so the logarithm is finite
+        let u = 1.0 - self.next_f64();
+        let v = self.next_f64();
+        (-2.0 * u.ln()).sqrt() * (std::f64::consts::TAU * v).cos()
+    }
+}
+
+/// Marks generators whose output cannot be predicted, i.e. cryptographically
+/// secure ones, as needed wherever randomness protects a secret.
+///
+/// None of the generators of this crate qualify: their whole output follows
+/// from the seed. Implement it for a wrapper around a vetted source such as
+/// the operating system's generator.
+pub trait CryptoRng: Rng {}
+
+/// The SplitMix64 generator: tiny, fast and fully determined by its seed.
+///
+/// See https://prng.di.unimi.it/splitmix64.c. It is not cryptographically
+/// secure.
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state : u64
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// The xoshiro256** generator, the general purpose generator of the crate.
+///
+/// See https://prng.di.unimi.it/. Its state is expanded from a 64-bit seed
+/// with [`SplitMix64`], as recommended by its authors. It is not
+/// cryptographically secure.
+#[derive(Debug, Clone)]
+pub struct Xoshiro256 {
+    s : [u64; 4]
+}
+
+impl Xoshiro256 {
+    pub fn new(seed: u64) -> Self {
+        let mut sm = SplitMix64::new(seed);
+        Xoshiro256 { s: [sm.next_u64(), sm.next_u64(), sm.next_u64(), sm.next_u64()] }
+    }
+}
+
+impl Rng for Xoshiro256 {
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+/// A polynomial of exactly the given degree whose coefficients are uniformly
+/// distributed in `[lo, hi)`.
+///
+/// # Panics
+///
+/// Panics if the range only contains zero.
+pub fn random_polynomial<T: Real, R: Rng>(rng: &mut R, degree: usize, lo: f64, hi: f64) -> Polynomial<T> {
+    let mut coeffs: Vec<T> = (0..degree).map(|_| T::from_f64(rng.uniform(lo, hi))).collect();
+    // redraw the leading coefficient until the degree is right
+    let lead = (0..1000)
+        .map(|_| T::from_f64(rng.uniform(lo, hi)))
+        .find(|c| !c.is_zero())
+        .expect("coefficient range contains only zero");
+    coeffs.push(lead);
+    Polynomial::new(coeffs)
+}
+
+/// `n` pairwise distinct nodes, uniformly distributed in `[lo, hi)` and
+/// returned in the order drawn.
+///
+/// # Panics
+///
+/// Panics if `[lo, hi)` does not contain `n` distinct values of `T`.
+pub fn random_nodes<T: Real, R: Rng>(rng: &mut R, n: usize, lo: f64, hi: f64) -> Vec<T> {
+    let mut nodes: Vec<T> = Vec::with_capacity(n);
+    let mut attempts = 0;
+    while nodes.len() < n {
+        attempts += 1;
+        assert!(attempts <= 1000 * (n + 1), "cannot draw {} distinct nodes from [{}, {})", n, lo, hi);
+        let x = T::from_f64(rng.uniform(lo, hi));
+        if !nodes.contains(&x) {
+            nodes.push(x);
+        }
+    }
+    nodes
+}
+
+/// `n` test points uniformly distributed in `[lo, hi)`.
+pub fn uniform_points<T: Real, R: Rng>(rng: &mut R, n: usize, lo: f64, hi: f64) -> Vec<T> {
+    (0..n).map(|_| T::from_f64(rng.uniform(lo, hi))).collect()
+}
+
+/// `n` test points normally distributed with the given mean and standard
+/// deviation.
+pub fn normal_points<T: Real, R: Rng>(rng: &mut R, n: usize, mean: f64, std_dev: f64) -> Vec<T> {
+    (0..n).map(|_| T::from_f64(mean + std_dev * rng.normal())).collect()
+}
+
+/// Samples of `f` at `n` random distinct nodes in `[lo, hi)`, ready to be
+/// interpolated.
+pub fn random_samples<T: Real, R: Rng>(rng: &mut R, n: usize, lo: f64, hi: f64, f: impl Fn(&T) -> T) -> Vec<Point<T>> {
+    random_nodes(rng, n, lo, hi)
+        .into_iter()
+        .map(|x| Point { y: f(&x), x })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NewtonPolynomial, PolyGetPoints, PolyInterpolate};
+
+    #[test]
+    fn generators_are_replayable_from_their_seed() {
+        let draw = |seed| {
+            let mut rng = Xoshiro256::new(seed);
+            (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(draw(42), draw(42));
+        assert_ne!(draw(42), draw(43));
+        // the first output of the reference implementation for seed 0
+        assert_eq!(SplitMix64::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn draws_stay_in_range() {
+        let mut rng = Xoshiro256::new(7);
+        assert!((0..1000).all(|_| rng.below(10) < 10));
+        assert!((0..1000).map(|_| rng.uniform(-2.0, 3.0)).all(|x| (-2.0..3.0).contains(&x)));
+        let nodes: Vec<f64> = random_nodes(&mut rng, 50, 0.0, 1.0);
+        assert!(nodes.iter().enumerate().all(|(i, x)| !nodes[..i].contains(x)));
+        let p: Polynomial<f64> = random_polynomial(&mut rng, 5, -1.0, 1.0);
+        assert_eq!(p.degree(), Some(5));
+    }
+
+    #[test]
+    fn random_interpolation_experiment() {
+        let mut rng = Xoshiro256::new(42);
+        let p: Polynomial<f64> = random_polynomial(&mut rng, 5, -10.0, 10.0);
+        let points = random_samples(&mut rng, 6, 10.0, 100.0, |x| p.get_y(x));
+        let np = NewtonPolynomial::interpolate(&points);
+        let xs: Vec<f64> = uniform_points(&mut rng, 90, 10.0, 100.0);
+        assert!(xs.iter().all(|x| ((p.get_y(x) - np.get_y(x)) / p.get_y(x)).abs() < 1e-6));
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::fft::{self, FFT_THRESHOLD};
+
+/// The field of numbers that points and polynomials are built from.
+///
+/// Interpolation only needs the field operations, so anything that behaves
+/// like a field can be used: floating point numbers, exact rationals or
+/// elements of a finite field. Division by zero is never attempted by the
+/// interpolators as long as their nodes are pairwise distinct.
+pub trait Scalar:
+    Clone
+    + Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity.
+    fn zero() -> Self;
+
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// The image of the integer `n` in the field.
+    fn from_i64(n: i64) -> Self;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// Whether the value is a proper field element; false for the infinities
+    /// and NaNs of floating point types.
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Whether the arithmetic is exact, as for rationals and finite fields.
+    /// Floating point types round and return false, so that checks against
+    /// rounding, such as for nearly coincident nodes, only apply to them.
+    fn is_exact() -> bool {
+        true
+    }
+
+    /// An approximation of the value as an `f64`, for fields that are a
+    /// subset of the reals, or `None` for fields such as GF(p) that are not.
+    fn approx_f64(&self) -> Option<f64> {
+        None
+    }
+
+    /// The coefficients of the product of the polynomials with coefficients
+    /// `a` and `b`, i.e. their convolution, as used by the multiplication of
+    /// [`Polynomial`](crate::Polynomial)s. The default is the schoolbook
+    /// method; floating point and complex numbers switch to the FFT for
+    /// long inputs.
+    fn mul_coeffs(a: &[Self], b: &[Self]) -> Vec<Self> {
+        schoolbook_mul(a, b)
+    }
+
+    /// `self` raised to the power `n`, by repeated squaring.
+    fn powi(&self, n: u32) -> Self {
+        let mut base = self.clone();
+        let mut acc = Self::one();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                acc = acc * base.clone();
+            }
+            base = base.clone() * base;
+            n >>= 1;
+        }
+        acc
+    }
+}
+
+/// A floating point [`Scalar`], for algorithms that need magnitudes and
+/// tolerances rather than exact arithmetic.
+pub trait Real: Scalar + Copy + PartialOrd {
+    /// The difference between 1 and the next representable number.
+    fn epsilon() -> Self;
+
+    fn abs(self) -> Self;
+
+    fn sqrt(self) -> Self;
+
+    /// `self * a + b` with a single rounding.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// The nearest representable value to `x`.
+    fn from_f64(x: f64) -> Self;
+
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_scalar_float {
+    ($t:ty) => {
+        impl Scalar for $t {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn one() -> Self {
+                1.0
+            }
+
+            fn from_i64(n: i64) -> Self {
+                n as $t
+            }
+
+            fn powi(&self, n: u32) -> Self {
+                <$t>::powi(*self, n as i32)
+            }
+
+            fn is_finite(&self) -> bool {
+                <$t>::is_finite(*self)
+            }
+
+            fn is_exact() -> bool {
+                false
+            }
+
+            fn approx_f64(&self) -> Option<f64> {
+                Some(*self as f64)
+            }
+
+            fn mul_coeffs(a: &[Self], b: &[Self]) -> Vec<Self> {
+                if a.len().min(b.len()) < FFT_THRESHOLD {
+                    schoolbook_mul(a, b)
+                } else {
+                    fft::multiply(a, b)
+                }
+            }
+        }
+
+        impl Real for $t {
+            fn epsilon() -> Self {
+                <$t>::EPSILON
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                <$t>::mul_add(self, a, b)
+            }
+
+            fn from_f64(x: f64) -> Self {
+                x as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    };
+}
+
+impl_scalar_float!(f32);
+impl_scalar_float!(f64);
+
+// schoolbook multiplication of coefficient vectors, in O(nm)
+pub(crate) fn schoolbook_mul<T: Scalar>(a: &[T], b: &[T]) -> Vec<T> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![T::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] = out[i + j].clone() + x.clone() * y.clone();
+        }
+    }
+    out
+}
@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::rng::CryptoRng;
+use crate::{Gf, LagrangePolynomial, Point, PolyGetPoints, PolyInterpolate, Polynomial, Scalar};
+
+// Shamir's secret sharing: a secret is the constant term of a random polynomial
+// of degree k - 1 over GF(P), and the shares are points on its graph. Any k
+// shares determine the polynomial by interpolation, fewer reveal nothing.
+// See https://en.wikipedia.org/wiki/Shamir%27s_secret_sharing
+
+/// The reasons [`split`] and [`combine`] can reject their input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShamirError {
+    /// The threshold `k` was zero.
+    ZeroThreshold,
+    /// Fewer shares than required were issued or supplied.
+    TooFewShares { needed: usize, got: usize },
+    /// More shares were requested than there are nonzero field elements.
+    TooManyShares { requested: usize, modulus: u64 },
+    /// The secret is not a canonical field element.
+    SecretOutOfRange { secret: u64, modulus: u64 },
+    /// A share has index zero, which is where the secret lives.
+    ZeroShareIndex { position: usize },
+    /// Two shares have the same index.
+    DuplicateShareIndex { index: u64, first: usize, second: usize },
+}
+
+impl fmt::Display for ShamirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShamirError::ZeroThreshold => write!(f, "the threshold must be at least 1"),
+            ShamirError::TooFewShares { needed, got } =>
+                write!(f, "need at least {} shares, got {}", needed, got),
+            ShamirError::TooManyShares { requested, modulus } =>
+                write!(f, "cannot issue {} shares over GF({})", requested, modulus),
+            ShamirError::SecretOutOfRange { secret, modulus } =>
+                write!(f, "secret {} is not below the modulus {}", secret, modulus),
+            ShamirError::ZeroShareIndex { position } =>
+                write!(f, "share {} has index 0", position),
+            ShamirError::DuplicateShareIndex { index, first, second } =>
+                write!(f, "shares {} and {} both have index {}", first, second, index),
+        }
+    }
+}
+
+impl Error for ShamirError {}
+
+/// Splits `secret` into `n` shares, any `k` of which reconstruct it.
+///
+/// The shares are the points `(i, f(i))` for `i = 1..=n`, where `f` is a
+/// polynomial of degree `k - 1` with constant term `secret` and the other
+/// coefficients drawn uniformly from `GF(P)` with `rng`.
+///
+/// The shares only hide the secret if these coefficients cannot be
+/// predicted, so `rng` must be a cryptographically secure generator; with
+/// a seeded one, anyone who knows or guesses the seed can recompute the
+/// polynomial and read off the secret.
+pub fn split<const P: u64, R: CryptoRng>(secret: u64, k: usize, n: usize, rng: &mut R)
+    -> Result<Vec<Point<Gf<P>>>, ShamirError>
+{
+    if k == 0 {
+        return Err(ShamirError::ZeroThreshold);
+    }
+    if n < k {
+        return Err(ShamirError::TooFewShares { needed: k, got: n });
+    }
+    if n as u128 >= P as u128 {
+        return Err(ShamirError::TooManyShares { requested: n, modulus: P });
+    }
+    if secret >= P {
+        return Err(ShamirError::SecretOutOfRange { secret, modulus: P });
+    }
+
+    let coeffs: Vec<Gf<P>> = std::iter::once(Gf::new(secret))
+        .chain((1..k).map(|_| Gf::new(rng.below(P))))
+        .collect();
+    let xs: Vec<Gf<P>> = (1..=n as u64).map(Gf::new).collect();
+
+    Ok(Polynomial::new(coeffs).get_points(&xs))
+}
+
+/// Reconstructs the secret from a set of shares produced by [`split`].
+///
+/// The result is only the original secret if at least `k` shares of the same
+/// split are supplied; with fewer shares any value is equally likely and
+/// there is no way to detect it.
+pub fn combine<const P: u64>(shares: &[Point<Gf<P>>]) -> Result<u64, ShamirError> {
+    if shares.is_empty() {
+        return Err(ShamirError::TooFewShares { needed: 1, got: 0 });
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if share.x.is_zero() {
+            return Err(ShamirError::ZeroShareIndex { position: i });
+        }
+        if let Some(j) = shares[..i].iter().position(|s| s.x == share.x) {
+            return Err(ShamirError::DuplicateShareIndex { index: share.x.value(), first: j, second: i });
+        }
+    }
+
+    Ok(LagrangePolynomial::interpolate(shares).get_y(&Gf::zero()).value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::{Rng, SplitMix64};
+
+    // predictable, so only good enough for tests
+    struct TestRng(SplitMix64);
+
+    impl Rng for TestRng {
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+    }
+
+    impl CryptoRng for TestRng {}
+
+    const M61: u64 = (1 << 61) - 1;
+
+    #[test]
+    fn any_k_shares_recover_the_secret() {
+        let mut rng = TestRng(SplitMix64::new(2024));
+        let shares = split::<M61, _>(271828, 3, 5, &mut rng).unwrap();
+        for subset in [[0, 1, 2], [4, 0, 2], [1, 3, 4]] {
+            let subset: Vec<_> = subset.iter().map(|&i| shares[i]).collect();
+            assert_eq!(combine(&subset), Ok(271828));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_rejected() {
+        let mut rng = TestRng(SplitMix64::new(1));
+        assert_eq!(split::<7, _>(1, 0, 3, &mut rng), Err(ShamirError::ZeroThreshold));
+        assert_eq!(split::<7, _>(1, 3, 2, &mut rng), Err(ShamirError::TooFewShares { needed: 3, got: 2 }));
+        assert_eq!(split::<7, _>(1, 2, 7, &mut rng), Err(ShamirError::TooManyShares { requested: 7, modulus: 7 }));
+        assert_eq!(split::<7, _>(7, 2, 3, &mut rng), Err(ShamirError::SecretOutOfRange { secret: 7, modulus: 7 }));
+        let share = |x, y| Point::new(Gf::<7>::new(x), Gf::new(y));
+        assert_eq!(combine::<7>(&[]), Err(ShamirError::TooFewShares { needed: 1, got: 0 }));
+        assert_eq!(combine(&[share(1, 2), share(0, 3)]), Err(ShamirError::ZeroShareIndex { position: 1 }));
+        assert_eq!(combine(&[share(1, 2), share(2, 3), share(1, 4)]),
+            Err(ShamirError::DuplicateShareIndex { index: 1, first: 0, second: 2 }));
+    }
+}
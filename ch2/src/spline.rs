@@ -0,0 +1,312 @@
+use crate::{Point, PolyGetPoints, PolyInterpolate, Polynomial, Real};
+
+// Cubic splines are piecewise cubics with continuous first and second
+// derivatives. They are determined by their second derivatives M_i at the
+// nodes (the moments), which satisfy a tridiagonal linear system
+//   h_{i-1} M_{i-1} + 2 (h_{i-1} + h_i) M_i + h_i M_{i+1} = 6 (s_i - s_{i-1})
+// with h_i = x_{i+1} - x_i and s_i = (y_{i+1} - y_i) / h_i, closed by two
+// boundary conditions. See https://en.wikipedia.org/wiki/Spline_interpolation
+// and de Boor, "A Practical Guide to Splines", Ch. IV.
+
+/// The two extra conditions that close the system for a cubic spline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplineBoundary<T = f32> {
+    /// Zero second derivative at both ends.
+    Natural,
+    /// Given first derivatives at the first and last node.
+    Clamped { start: T, end: T },
+    /// Equal first and second derivatives at both ends, for periodic data
+    /// whose first and last values agree. Values that agree up to rounding,
+    /// such as `sin` at `0` and `2 pi`, are accepted and the last one is
+    /// replaced by the first.
+    Periodic,
+    /// A continuous third derivative at the second and the second to last
+    /// node, so that the first two and the last two segments are single
+    /// cubics.
+    NotAKnot,
+}
+
+/// The cubic `a + b t + c t^2 + d t^3` in `t = x - start` that a spline
+/// follows on `[start, end]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplineSegment<T = f32> {
+    pub start : T,
+    pub end : T,
+    /// The coefficients `[a, b, c, d]`.
+    pub coeffs : [T; 4]
+}
+
+impl<T: Real> SplineSegment<T> {
+    /// The cubic of the segment as a polynomial in `t = x - start`.
+    pub fn polynomial(&self) -> Polynomial<T> {
+        Polynomial::new(self.coeffs)
+    }
+
+    // value, first and second derivative at x
+    fn eval(&self, x: T) -> (T, T, T) {
+        let [a, b, c, d] = self.coeffs;
+        let t = x - self.start;
+        let (two, three) = (T::from_i64(2), T::from_i64(3));
+        (
+            a + t * (b + t * (c + t * d)),
+            b + t * (two * c + t * three * d),
+            two * c + T::from_i64(6) * d * t,
+        )
+    }
+}
+
+/// A cubic spline interpolating a set of points.
+///
+/// The points are sorted by x and must have pairwise distinct x coordinates;
+/// this is not checked. Outside the nodes the spline continues the cubic of
+/// the nearest segment.
+#[derive(Debug, Clone)]
+pub struct CubicSpline<T = f32> {
+    segments : Vec<SplineSegment<T>>
+}
+
+impl<T: Real> CubicSpline<T> {
+    /// The spline through `points` with the given boundary conditions, in
+    /// O(n).
+    ///
+    /// With fewer than four points, the not-a-knot spline is the
+    /// interpolating polynomial of degree at most two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a periodic spline is requested for points whose first and
+    /// last values differ by more than `sqrt(epsilon)` times the largest
+    /// magnitude of the values.
+    pub fn with_boundary(points: &[Point<T>], boundary: SplineBoundary<T>) -> Self {
+        let mut points = points.to_vec();
+        points.sort_by(|p, q| p.x.partial_cmp(&q.x).unwrap_or(std::cmp::Ordering::Equal));
+        let (xs, mut ys): (Vec<T>, Vec<T>) = points.iter().map(|p| (p.x, p.y)).unzip();
+        match xs.len() {
+            0 => return CubicSpline { segments: Vec::new() },
+            1 => {
+                let zero = T::zero();
+                let segment = SplineSegment { start: xs[0], end: xs[0], coeffs: [ys[0], zero, zero, zero] };
+                return CubicSpline { segments: vec![segment] };
+            }
+            _ => {}
+        }
+        if boundary == SplineBoundary::Periodic {
+            let last = ys.len() - 1;
+            let scale = ys.iter().fold(T::zero(), |acc, y| if y.abs() > acc { y.abs() } else { acc });
+            assert!((ys[last] - ys[0]).abs() <= T::epsilon().sqrt() * scale, "the end values of a periodic spline differ");
+            ys[last] = ys[0];
+        }
+        let moments = moments(&xs, &ys, boundary);
+        let six = T::from_i64(6);
+        let segments = (0..xs.len() - 1)
+            .map(|i| {
+                let h = xs[i + 1] - xs[i];
+                let (m0, m1) = (moments[i], moments[i + 1]);
+                let b = (ys[i + 1] - ys[i]) / h - h * (T::from_i64(2) * m0 + m1) / six;
+                let coeffs = [ys[i], b, m0 / T::from_i64(2), (m1 - m0) / (six * h)];
+                SplineSegment { start: xs[i], end: xs[i + 1], coeffs }
+            })
+            .collect();
+        CubicSpline { segments }
+    }
+
+    // the segment whose cubic is used at x
+    fn segment(&self, x: T) -> Option<&SplineSegment<T>> {
+        let i = self.segments.partition_point(|s| s.end <= x);
+        self.segments.get(i.min(self.segments.len().saturating_sub(1)))
+    }
+
+    /// The first derivative of the spline at `x`.
+    pub fn derivative(&self, x: &T) -> T {
+        self.segment(*x).map_or(T::zero(), |s| s.eval(*x).1)
+    }
+
+    /// The second derivative of the spline at `x`.
+    pub fn second_derivative(&self, x: &T) -> T {
+        self.segment(*x).map_or(T::zero(), |s| s.eval(*x).2)
+    }
+
+    /// The segments between consecutive nodes, in increasing order.
+    pub fn segments(&self) -> &[SplineSegment<T>] {
+        &self.segments
+    }
+}
+
+// The second derivatives at the nodes, for at least two nodes.
+fn moments<T: Real>(xs: &[T], ys: &[T], boundary: SplineBoundary<T>) -> Vec<T> {
+    let n = xs.len() - 1; // the number of segments
+    let (two, six) = (T::from_i64(2), T::from_i64(6));
+    let h: Vec<T> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+    let s: Vec<T> = (0..n).map(|i| (ys[i + 1] - ys[i]) / h[i]).collect();
+
+    // the rows of the interior nodes 1..n
+    let mut sub: Vec<T> = (1..n).map(|i| h[i - 1]).collect();
+    let mut diag: Vec<T> = (1..n).map(|i| two * (h[i - 1] + h[i])).collect();
+    let mut sup: Vec<T> = (1..n).map(|i| h[i]).collect();
+    let mut rhs: Vec<T> = (1..n).map(|i| six * (s[i] - s[i - 1])).collect();
+
+    match boundary {
+        SplineBoundary::Natural => {
+            let mut m = solve_tridiagonal(&sub, &diag, &sup, &rhs);
+            m.insert(0, T::zero());
+            m.push(T::zero());
+            m
+        }
+        SplineBoundary::Clamped { start, end } => {
+            sub.insert(0, T::zero());
+            diag.insert(0, two * h[0]);
+            sup.insert(0, h[0]);
+            rhs.insert(0, six * (s[0] - start));
+            sub.push(h[n - 1]);
+            diag.push(two * h[n - 1]);
+            sup.push(T::zero());
+            rhs.push(six * (end - s[n - 1]));
+            solve_tridiagonal(&sub, &diag, &sup, &rhs)
+        }
+        SplineBoundary::Periodic => {
+            // M_0 = M_n, and node 0 gets the row of an interior node with
+            // x_{-1} = x_{n-1} - (x_n - x_0)
+            sub.insert(0, h[n - 1]);
+            diag.insert(0, two * (h[n - 1] + h[0]));
+            sup.insert(0, h[0]);
+            rhs.insert(0, six * (s[0] - s[n - 1]));
+            let mut m = solve_cyclic(&sub, &diag, &sup, &rhs);
+            m.push(m[0]);
+            m
+        }
+        SplineBoundary::NotAKnot if n < 3 => {
+            // the interpolating polynomial: a line or a parabola
+            let m = if n == 1 { T::zero() } else { two * (s[1] - s[0]) / (h[0] + h[1]) };
+            vec![m; n + 1]
+        }
+        SplineBoundary::NotAKnot => {
+            // a continuous third derivative at x_1 means
+            // M_0 = ((h_0 + h_1) M_1 - h_0 M_2) / h_1, which is substituted
+            // into the first row, and likewise at x_{n-1} into the last
+            diag[0] = (h[0] + h[1]) * (h[0] + two * h[1]) / h[1];
+            sup[0] = (h[1] * h[1] - h[0] * h[0]) / h[1];
+            let (a, b) = (h[n - 2], h[n - 1]);
+            diag[n - 2] = (a + b) * (b + two * a) / a;
+            sub[n - 2] = (a * a - b * b) / a;
+            let mut m = solve_tridiagonal(&sub, &diag, &sup, &rhs);
+            m.insert(0, ((h[0] + h[1]) * m[0] - h[0] * m[1]) / h[1]);
+            m.push(((a + b) * m[n - 1] - b * m[n - 2]) / a);
+            m
+        }
+    }
+}
+
+// Solves the tridiagonal system with diagonal `diag`, subdiagonal `sub` and
+// superdiagonal `sup` by the Thomas algorithm, Gaussian elimination without
+// pivoting. `sub[0]` and the last entry of `sup` are ignored. The spline
+// systems are diagonally dominant, so no pivoting is needed.
+// See https://en.wikipedia.org/wiki/Tridiagonal_matrix_algorithm
+fn solve_tridiagonal<T: Real>(sub: &[T], diag: &[T], sup: &[T], rhs: &[T]) -> Vec<T> {
+    let n = diag.len();
+    let mut c: Vec<T> = Vec::with_capacity(n);
+    let mut d: Vec<T> = Vec::with_capacity(n);
+    for i in 0..n {
+        let (prev_c, prev_d) = if i == 0 { (T::zero(), T::zero()) } else { (c[i - 1], d[i - 1]) };
+        let a = if i == 0 { T::zero() } else { sub[i] };
+        let denom = diag[i] - a * prev_c;
+        c.push(sup[i] / denom);
+        d.push((rhs[i] - a * prev_d) / denom);
+    }
+    for i in (0..n.saturating_sub(1)).rev() {
+        d[i] = d[i] - c[i] * d[i + 1];
+    }
+    d
+}
+
+// Solves the cyclic tridiagonal system in which `sub[0]` is the entry in the
+// last column of the first row and the last entry of `sup` the entry in the
+// first column of the last row, by the Sherman-Morrison formula.
+// See Press et al., "Numerical Recipes", Sec. 2.7.
+fn solve_cyclic<T: Real>(sub: &[T], diag: &[T], sup: &[T], rhs: &[T]) -> Vec<T> {
+    let n = diag.len();
+    let (alpha, beta) = (sup[n - 1], sub[0]);
+    if n <= 2 {
+        // the corners coincide with the band
+        let mut diag = diag.to_vec();
+        let (mut sub, mut sup) = (sub.to_vec(), sup.to_vec());
+        if n == 1 {
+            diag[0] = diag[0] + alpha + beta;
+        } else {
+            sup[0] = sup[0] + beta;
+            sub[1] = sub[1] + alpha;
+        }
+        return solve_tridiagonal(&sub, &diag, &sup, rhs);
+    }
+    // A = B + u v^T with u = (gamma, 0, ..., 0, alpha) and
+    // v = (1, 0, ..., 0, beta / gamma)
+    let gamma = -diag[0];
+    let mut b = diag.to_vec();
+    b[0] = diag[0] - gamma;
+    b[n - 1] = diag[n - 1] - alpha * beta / gamma;
+    let x = solve_tridiagonal(sub, &b, sup, rhs);
+    let mut u = vec![T::zero(); n];
+    u[0] = gamma;
+    u[n - 1] = alpha;
+    let z = solve_tridiagonal(sub, &b, sup, &u);
+    let factor = (x[0] + beta * x[n - 1] / gamma) / (T::one() + z[0] + beta * z[n - 1] / gamma);
+    x.iter().zip(&z).map(|(&x, &z)| x - factor * z).collect()
+}
+
+impl<T: Real> PolyInterpolate<T> for CubicSpline<T> {
+    /// The natural cubic spline through `points`.
+    fn interpolate(points: &[Point<T>]) -> Self {
+        CubicSpline::with_boundary(points, SplineBoundary::Natural)
+    }
+}
+
+impl<T: Real> PolyGetPoints<T> for CubicSpline<T> {
+    fn get_y(&self, x: &T) -> T {
+        self.segment(*x).map_or(T::zero(), |s| s.eval(*x).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::TAU;
+
+    fn samples(n: usize, a: f64, b: f64, f: impl Fn(f64) -> f64) -> Vec<Point<f64>> {
+        (0..n).map(|i| a + (b - a) * i as f64 / (n - 1) as f64).map(|x| Point::new(x, f(x))).collect()
+    }
+
+    #[test]
+    fn periodic_spline_of_rounded_periodic_data() {
+        let points = samples(17, 0.0, TAU, f64::sin);
+        assert_ne!(points[0].y, points[16].y);
+        let spline = CubicSpline::with_boundary(&points, SplineBoundary::Periodic);
+        assert_eq!(spline.get_y(&TAU), 0.0);
+        assert!((spline.derivative(&0.0) - spline.derivative(&TAU)).abs() < 1e-12);
+        assert!((spline.second_derivative(&0.0) - spline.second_derivative(&TAU)).abs() < 1e-12);
+        assert!((0..100).map(|i| TAU * i as f64 / 100.0).all(|x| (spline.get_y(&x) - x.sin()).abs() < 1e-3));
+    }
+
+    #[test]
+    #[should_panic(expected = "the end values of a periodic spline differ")]
+    fn periodic_spline_of_non_periodic_data_panics() {
+        let points = [(0.0, 0.0), (1.0, 1.0), (2.0, 4.0)].map(|(x, y)| Point::new(x, y));
+        CubicSpline::with_boundary(&points, SplineBoundary::Periodic);
+    }
+
+    #[test]
+    fn cubics_are_reproduced() {
+        let cubic = |x: f64| 1.0 - 2.0 * x + 0.5 * x * x * x;
+        let dcubic = |x: f64| -2.0 + 1.5 * x * x;
+        let points = samples(7, -1.0, 2.0, cubic);
+        for boundary in [SplineBoundary::NotAKnot, SplineBoundary::Clamped { start: dcubic(-1.0), end: dcubic(2.0) }] {
+            let spline = CubicSpline::with_boundary(&points, boundary);
+            assert!((0..=30).map(|i| -1.0 + 0.1 * i as f64).all(|x| (spline.get_y(&x) - cubic(x)).abs() < 1e-12), "{:?}", boundary);
+        }
+    }
+
+    #[test]
+    fn natural_spline_has_straight_ends() {
+        let spline = CubicSpline::interpolate(&samples(5, 0.0, 4.0, |x| x * x));
+        assert_eq!(spline.second_derivative(&0.0), 0.0);
+        assert!(spline.second_derivative(&4.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,359 @@
+use crate::{Polynomial, Scalar};
+
+// Real root isolation with Sturm sequences.
+//
+// The Sturm sequence of p is p_0 = p, p_1 = p' and p_{i+1} = -rem(p_{i-1}, p_i)
+// down to the last nonzero remainder. If V(x) is the number of sign changes in
+// p_0(x), p_1(x), ..., ignoring zeros, then a square-free p has exactly
+// V(a) - V(b) distinct roots in (a, b]. Bisecting from an interval containing
+// all roots until every piece holds at most one of them isolates the roots.
+// See https://en.wikipedia.org/wiki/Sturm%27s_theorem
+//
+// Only the field operations and comparisons are used, so roots of exact
+// polynomials are isolated exactly. Over floating point numbers the remainders
+// are rounded and the counts can be wrong for clustered or multiple roots.
+
+/// A real root of a polynomial, bracketed by the interval `(lo, hi]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealRoot<T = f32> {
+    /// The best known approximation of the root, within `(lo, hi]`.
+    pub value : T,
+    pub lo : T,
+    pub hi : T,
+    /// The multiplicity of the root, as far as it could be determined. Over
+    /// floating point numbers it is usually 1, since rounding splits multiple
+    /// roots; it is also the number of roots in the interval when they could
+    /// not be separated at the available precision.
+    pub multiplicity : usize
+}
+
+fn abs<T: Scalar + PartialOrd>(x: T) -> T {
+    if x < T::zero() { -x } else { x }
+}
+
+// -1, 0 or 1
+fn sign<T: Scalar + PartialOrd>(x: &T) -> i8 {
+    if *x > T::zero() {
+        1
+    } else if *x < T::zero() {
+        -1
+    } else {
+        0
+    }
+}
+
+fn midpoint<T: Scalar>(a: &T, b: &T) -> T {
+    (a.clone() + b.clone()) / T::from_i64(2)
+}
+
+// The number of sign changes in the sequence evaluated at x, skipping zeros.
+fn variations<T: Scalar + PartialOrd>(sequence: &[Polynomial<T>], x: &T) -> usize {
+    let signs: Vec<i8> = sequence.iter()
+        .map(|p| sign(&p.horner(x)))
+        .filter(|s| *s != 0)
+        .collect();
+    signs.windows(2).filter(|w| w[0] != w[1]).count()
+}
+
+impl<T: Scalar + PartialOrd> Polynomial<T> {
+    /// The Sturm sequence `p, p', -rem(p, p'), ...`, ending in a multiple of
+    /// `gcd(p, p')`. It is empty for the zero polynomial.
+    pub fn sturm_sequence(&self) -> Vec<Polynomial<T>> {
+        let mut sequence = Vec::new();
+        if self.is_zero() {
+            return sequence;
+        }
+        let (mut a, mut b) = (self.clone(), self.derivative(1));
+        while !b.is_zero() {
+            let r = -a.div_rem(&b).1;
+            sequence.push(a);
+            (a, b) = (b, r);
+        }
+        sequence.push(a);
+        sequence
+    }
+
+    /// The polynomial divided by `gcd(p, p')`, which has the same roots but
+    /// all of them simple.
+    pub fn square_free_part(&self) -> Polynomial<T> {
+        if self.is_zero() {
+            return Polynomial::zero();
+        }
+        self.div_rem(&self.gcd(&self.derivative(1))).0
+    }
+
+    /// The number of distinct real roots in `(a, b]`, or zero if `a >= b`.
+    ///
+    /// The zero polynomial is taken to have no roots.
+    pub fn count_real_roots(&self, a: &T, b: &T) -> usize {
+        if a >= b {
+            return 0;
+        }
+        let sequence = self.square_free_part().sturm_sequence();
+        variations(&sequence, a).saturating_sub(variations(&sequence, b))
+    }
+
+    /// A bound `B` such that all roots, real or complex, satisfy `|z| < B`:
+    /// Cauchy's bound `1 + max |a_i / a_n|`.
+    pub fn cauchy_bound(&self) -> T {
+        let Some(lc) = self.leading_coeff() else {
+            return T::one();
+        };
+        let n = self.coeffs().len() - 1;
+        self.coeffs()[..n].iter()
+            .map(|c| abs(c.clone() / lc.clone()))
+            .fold(T::zero(), |acc, c| if c > acc { c } else { acc })
+            + T::one()
+    }
+
+    /// Disjoint intervals `(lo, hi]` each containing exactly one distinct
+    /// real root, in increasing order. No root lies on an endpoint, and
+    /// `value` is the midpoint of the interval.
+    ///
+    /// The zero polynomial is taken to have no roots.
+    pub fn isolate_real_roots(&self) -> Vec<RealRoot<T>> {
+        let q = self.square_free_part();
+        let sequence = q.sturm_sequence();
+        if sequence.is_empty() {
+            return Vec::new();
+        }
+        let bound = q.cauchy_bound();
+        let (lo, hi) = (-bound.clone(), bound);
+        let mut roots = Vec::new();
+        // (lo, hi, number of roots inside), processed left to right
+        let n = variations(&sequence, &lo).saturating_sub(variations(&sequence, &hi));
+        let mut stack = vec![(lo, hi, n)];
+        while let Some((lo, hi, n)) = stack.pop() {
+            if n == 0 {
+                continue;
+            }
+            if n == 1 {
+                roots.push(RealRoot { value: midpoint(&lo, &hi), lo, hi, multiplicity: 1 });
+                continue;
+            }
+            // split at a point that is not a root, so that endpoints never are;
+            // q has finitely many roots, so one of lo + (hi - lo) / k works
+            let Some(mid) = (2..)
+                .map(|k| lo.clone() + (hi.clone() - lo.clone()) / T::from_i64(k))
+                .take_while(|m| *m > lo && *m < hi)
+                .find(|m| !q.horner(m).is_zero()) else {
+                // no representable split point, the roots cannot be separated
+                roots.push(RealRoot { value: midpoint(&lo, &hi), lo, hi, multiplicity: n });
+                continue;
+            };
+            let left = variations(&sequence, &lo).saturating_sub(variations(&sequence, &mid)).min(n);
+            stack.push((mid.clone(), hi, n - left));
+            stack.push((lo, mid, left));
+        }
+        let multiplicities = MultiplicityCounter::new(self);
+        for root in roots.iter_mut().filter(|r| r.multiplicity == 1) {
+            root.multiplicity = multiplicities.count(&root.lo, &root.hi);
+        }
+        roots
+    }
+
+    /// All distinct real roots in increasing order, each refined until its
+    /// bracket is at most `tol` wide. `value` is then the midpoint of the
+    /// bracket, or the root itself when it was hit exactly, in which case
+    /// `lo == hi`.
+    ///
+    /// Refinement is bisection accelerated by Newton's method on the
+    /// square-free part.
+    pub fn real_roots(&self, tol: &T) -> Vec<RealRoot<T>> {
+        let q = self.square_free_part();
+        let dq = q.derivative(1);
+        self.isolate_real_roots()
+            .into_iter()
+            .map(|root| if root.multiplicity > 1 && q.count_real_roots(&root.lo, &root.hi) > 1 {
+                root
+            } else {
+                refine(&q, &dq, root, tol)
+            })
+            .collect()
+    }
+}
+
+// Narrows the bracket of a simple root of q with a sign change across it.
+//
+// Each step bisects, and if Newton's method from the midpoint lands inside the
+// bracket, also cuts at the two points of a grid of sixteenths of the bracket
+// around the Newton iterate. Plain Newton iterates would square the size of
+// exact rationals at every step, while grid points keep them small; near the
+// root the bracket still shrinks 32-fold per step.
+fn refine<T: Scalar + PartialOrd>(q: &Polynomial<T>, dq: &Polynomial<T>, mut root: RealRoot<T>, tol: &T) -> RealRoot<T> {
+    let (lo_sign, hi) = (sign(&q.horner(&root.lo)), root.hi.clone());
+    if !cut(q, lo_sign, &mut root, hi) {
+        let sixteen = T::from_i64(16);
+        while root.hi.clone() - root.lo.clone() > *tol {
+            let mid = midpoint(&root.lo, &root.hi);
+            if mid <= root.lo || mid >= root.hi {
+                break;
+            }
+            let dy = dq.horner(&mid);
+            let newton = (!dy.is_zero()).then(|| mid.clone() - q.horner(&mid) / dy);
+            let (lo, width) = (root.lo.clone(), root.hi.clone() - root.lo.clone());
+            if cut(q, lo_sign, &mut root, mid) {
+                break;
+            }
+            let Some(t) = newton.filter(|t| *t > root.lo && *t < root.hi) else {
+                continue;
+            };
+            let grid: Vec<T> = (1..16)
+                .map(|j| lo.clone() + width.clone() * T::from_i64(j) / sixteen.clone())
+                .collect();
+            let j = grid.partition_point(|g| *g < t);
+            if j > 0 && cut(q, lo_sign, &mut root, grid[j - 1].clone()) {
+                break;
+            }
+            if j < grid.len() && cut(q, lo_sign, &mut root, grid[j].clone()) {
+                break;
+            }
+        }
+    }
+    root.value = if root.lo == root.hi { root.hi.clone() } else { midpoint(&root.lo, &root.hi) };
+    root
+}
+
+// Shrinks the bracket of the root to one side of x, if x lies inside it.
+// Returns whether x is the root itself, which then becomes the bracket.
+fn cut<T: Scalar + PartialOrd>(q: &Polynomial<T>, lo_sign: i8, root: &mut RealRoot<T>, x: T) -> bool {
+    if x <= root.lo || x > root.hi {
+        return false;
+    }
+    match sign(&q.horner(&x)) {
+        0 => {
+            root.lo = x.clone();
+            root.hi = x;
+            true
+        }
+        s if s == lo_sign => {
+            root.lo = x;
+            false
+        }
+        _ => {
+            root.hi = x;
+            false
+        }
+    }
+}
+
+// The multiplicity of a root is the number of polynomials in
+// p, gcd(p, p'), gcd(g, g'), ... that vanish at it.
+struct MultiplicityCounter<T> {
+    sequences : Vec<Vec<Polynomial<T>>>
+}
+
+impl<T: Scalar + PartialOrd> MultiplicityCounter<T> {
+    fn new(p: &Polynomial<T>) -> Self {
+        let mut sequences = Vec::new();
+        let mut g = p.gcd(&p.derivative(1));
+        while g.degree().is_some_and(|d| d > 0) {
+            sequences.push(g.square_free_part().sturm_sequence());
+            g = g.gcd(&g.derivative(1));
+        }
+        MultiplicityCounter { sequences }
+    }
+
+    // the multiplicity of the only root of p in (lo, hi]
+    fn count(&self, lo: &T, hi: &T) -> usize {
+        1 + self.sequences.iter()
+            .take_while(|s| variations(s, lo) > variations(s, hi))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NewtonPolynomial, Point, PolyGetPoints, PolyInterpolate, Rational};
+
+    fn poly(coeffs: &[i64]) -> Polynomial<Rational> {
+        Polynomial::new(coeffs.iter().map(|&c| Rational::from(c)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn roots_are_counted_and_isolated_exactly() {
+        // (x + 2)(x - 1)^2 (x - 3) (x^2 + 1)
+        let p = [poly(&[2, 1]), poly(&[-1, 1]), poly(&[-1, 1]), poly(&[-3, 1]), poly(&[1, 0, 1])]
+            .into_iter()
+            .fold(Polynomial::one(), |acc, f| acc * f);
+        let q = Rational::from;
+        assert_eq!(p.count_real_roots(&q(-10), &q(10)), 3);
+        assert_eq!(p.count_real_roots(&q(-2), &q(1)), 1);
+        assert_eq!(p.count_real_roots(&q(-3), &q(1)), 2);
+        let roots = p.isolate_real_roots();
+        let found: Vec<(Rational, Rational, usize)> = roots.iter().map(|r| (r.lo.clone(), r.hi.clone(), r.multiplicity)).collect();
+        assert_eq!(found.len(), 3);
+        for (root, (lo, hi, m)) in [(-2, 1), (1, 2), (3, 1)].iter().zip(found) {
+            assert!(lo < q(root.0) && q(root.0) <= hi, "{} not in ({}, {}]", root.0, lo, hi);
+            assert_eq!(m, root.1);
+        }
+        let tol = Rational::new(1, 1000);
+        let refined = p.real_roots(&tol);
+        assert_eq!(refined.len(), 3);
+        for (r, root) in refined.iter().zip([-2, 1, 3]) {
+            assert!(r.lo <= q(root) && q(root) <= r.hi && r.hi.clone() - r.lo.clone() <= tol);
+        }
+    }
+
+    #[test]
+    fn roots_of_the_book_interpolant() {
+        let s = |s: &str| s.parse::<Rational>().unwrap();
+        let p = Polynomial::new([s("1.9"), s("9.2"), s("7")]);
+        let points: Vec<Point<Rational>> = p.get_points(&[s("1.8"), s("37.2"), s("80.9")]);
+        let tol = s("0.000001");
+        let roots = NewtonPolynomial::interpolate(&points).to_monomial().real_roots(&tol);
+        // 7x^2 + 9.2x + 1.9 = 0 at x = (-46 -+ sqrt(786)) / 70
+        let expected = [(-46.0 - 786_f64.sqrt()) / 70.0, (-46.0 + 786_f64.sqrt()) / 70.0];
+        assert_eq!(roots.len(), 2);
+        for (root, x) in roots.iter().zip(expected) {
+            assert!(root.hi.clone() - root.lo.clone() <= tol);
+            assert!((root.value.to_f64() - x).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn polynomials_without_real_roots() {
+        assert!(poly(&[1, 0, 1]).isolate_real_roots().is_empty());
+        assert!(Polynomial::<Rational>::zero().isolate_real_roots().is_empty());
+        assert!(poly(&[5]).real_roots(&Rational::from(1)).is_empty());
+    }
+
+    fn lin(r: f64) -> Polynomial<f64> {
+        Polynomial::new([-r, 1.0])
+    }
+
+    #[test]
+    fn simple_float_roots_are_refined_to_tolerance() {
+        let p = &(&lin(1.0) * &lin(2.0)) * &lin(3.0);
+        assert_eq!(p.count_real_roots(&0.0, &10.0), 3);
+        let roots = p.real_roots(&1e-12);
+        assert_eq!(roots.len(), 3);
+        for (r, root) in roots.iter().zip([1.0, 2.0, 3.0]) {
+            assert!(r.lo <= root && root <= r.hi && r.hi - r.lo <= 1e-12, "{:?}", r);
+            assert!((r.value - root).abs() <= 1e-12);
+            assert_eq!(r.multiplicity, 1);
+        }
+    }
+
+    #[test]
+    fn float_double_roots() {
+        // with exactly representable coefficients the remainders are exact,
+        // so the double root is found as one root with multiplicity 2
+        let p = &(&lin(1.0) * &lin(1.0)) * &lin(-2.0);
+        let roots = p.real_roots(&1e-12);
+        let found: Vec<(f64, usize)> = roots.iter().map(|r| (r.value, r.multiplicity)).collect();
+        assert_eq!(found.len(), 2, "{:?}", roots);
+        assert!((found[0].0 + 2.0).abs() < 1e-12 && found[0].1 == 1, "{:?}", roots);
+        assert!(roots[1].lo <= 1.0 && 1.0 <= roots[1].hi && found[1].1 == 2, "{:?}", roots);
+        // with rounded coefficients the double root splits into two simple
+        // roots close to it, as documented for RealRoot::multiplicity
+        let p = &(&lin(0.1) * &lin(0.1)) * &lin(3.0);
+        let roots = p.real_roots(&1e-12);
+        assert_eq!(roots.len(), 3, "{:?}", roots);
+        for r in &roots[..2] {
+            assert!((r.value - 0.1).abs() < 1e-7 && r.multiplicity == 1, "{:?}", r);
+        }
+        assert!((roots[2].value - 3.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,16 @@
+// Fixtures shared by the unit tests of several modules.
+
+use crate::{Point, PolyGetPoints, Polynomial, Rational};
+
+/// Parses an exact rational such as `"1.8"` or `"-3/4"`.
+pub(crate) fn q(s: &str) -> Rational {
+    s.parse().unwrap()
+}
+
+/// The experiment of the book, in exact arithmetic: the polynomial
+/// `1.9 + 9.2x + 7x^2` and its values at `1.8`, `37.2` and `80.9`.
+pub(crate) fn book_points() -> (Polynomial<Rational>, Vec<Point<Rational>>) {
+    let p = Polynomial::new([q("1.9"), q("9.2"), q("7")]);
+    let points = p.get_points(&[q("1.8"), q("37.2"), q("80.9")]);
+    (p, points)
+}
@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{Point, Scalar};
+
+/// The reasons a set of points cannot be interpolated.
+///
+/// Indices refer to positions in the slice passed to
+/// [`try_interpolate`](crate::PolyInterpolate::try_interpolate); for pairs,
+/// `first < second`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterpolationError {
+    /// There are no points.
+    Empty,
+    /// A coordinate of the point is infinite or NaN.
+    NonFinite { index: usize },
+    /// Two points are identical. This is harmless for the interpolant, so
+    /// dropping one of them is a valid fix.
+    DuplicateNode { first: usize, second: usize },
+    /// Two points have the same x but different y, so no function passes
+    /// through both.
+    ConflictingNodes { first: usize, second: usize },
+    /// Two nodes are closer than the tolerance of [`Validation`] allows,
+    /// relative to the spread of all nodes.
+    NearlyCoincident { first: usize, second: usize, relative_gap: f64 },
+}
+
+impl fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpolationError::Empty => write!(f, "no points to interpolate"),
+            InterpolationError::NonFinite { index } =>
+                write!(f, "point {} has a non-finite coordinate", index),
+            InterpolationError::DuplicateNode { first, second } =>
+                write!(f, "points {} and {} are identical", first, second),
+            InterpolationError::ConflictingNodes { first, second } =>
+                write!(f, "points {} and {} have the same x but different y", first, second),
+            InterpolationError::NearlyCoincident { first, second, relative_gap } =>
+                write!(f, "nodes {} and {} are nearly coincident (relative gap {:e})", first, second, relative_gap),
+        }
+    }
+}
+
+impl Error for InterpolationError {}
+
+/// Checks applied by [`try_interpolate_with`](crate::PolyInterpolate::try_interpolate_with).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Validation {
+    /// Two nodes are rejected as nearly coincident if their distance is at
+    /// most this fraction of the distance between the outermost nodes. The
+    /// check only applies to inexact scalars with an
+    /// [`approx_f64`](Scalar::approx_f64), i.e. floating point numbers, since
+    /// exact arithmetic handles close nodes without loss. It is disabled by
+    /// zero.
+    pub min_relative_gap : f64
+}
+
+impl Default for Validation {
+    fn default() -> Self {
+        Validation { min_relative_gap: 1e-10 }
+    }
+}
+
+impl Validation {
+    /// Checks that `points` can be interpolated: it must be non-empty, finite
+    /// and have pairwise distinct, well-separated nodes.
+    pub fn check<T: Scalar>(&self, points: &[Point<T>]) -> Result<(), InterpolationError> {
+        if points.is_empty() {
+            return Err(InterpolationError::Empty);
+        }
+        if let Some(index) = points.iter().position(|p| !p.x.is_finite() || !p.y.is_finite()) {
+            return Err(InterpolationError::NonFinite { index });
+        }
+        for (second, p) in points.iter().enumerate() {
+            if let Some(first) = points[..second].iter().position(|q| q.x == p.x) {
+                return Err(if points[first].y == p.y {
+                    InterpolationError::DuplicateNode { first, second }
+                } else {
+                    InterpolationError::ConflictingNodes { first, second }
+                });
+            }
+        }
+        self.check_separation(points)
+    }
+
+    fn check_separation<T: Scalar>(&self, points: &[Point<T>]) -> Result<(), InterpolationError> {
+        if T::is_exact() {
+            return Ok(());
+        }
+        let Some(mut xs) = points.iter()
+            .enumerate()
+            .map(|(i, p)| p.x.approx_f64().map(|x| (x, i)))
+            .collect::<Option<Vec<(f64, usize)>>>() else {
+            return Ok(());
+        };
+        xs.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let spread = xs[xs.len() - 1].0 - xs[0].0;
+        for w in xs.windows(2) {
+            let relative_gap = (w[1].0 - w[0].0) / spread;
+            if relative_gap <= self.min_relative_gap {
+                let (first, second) = (w[0].1.min(w[1].1), w[0].1.max(w[1].1));
+                return Err(InterpolationError::NearlyCoincident { first, second, relative_gap });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Gf, Rational};
+
+    #[test]
+    fn rejects_empty_non_finite_and_repeated_nodes() {
+        let v = Validation::default();
+        assert_eq!(v.check::<f64>(&[]), Err(InterpolationError::Empty));
+        assert_eq!(v.check(&[Point::new(0.0, 1.0), Point::new(1.0, f64::NAN)]), Err(InterpolationError::NonFinite { index: 1 }));
+        let points = [Point::new(1.0, 2.0), Point::new(3.0, 1.0), Point::new(1.0, 2.0)];
+        assert_eq!(v.check(&points), Err(InterpolationError::DuplicateNode { first: 0, second: 2 }));
+        let points = [Point::new(Gf::<7>::new(1), Gf::new(2)), Point::new(Gf::new(8), Gf::new(3))];
+        assert_eq!(v.check(&points), Err(InterpolationError::ConflictingNodes { first: 0, second: 1 }));
+    }
+
+    #[test]
+    fn close_nodes_are_only_rejected_for_floats() {
+        let v = Validation::default();
+        let points = [Point::new(0.0, 0.0), Point::new(1e-11, 1.0), Point::new(1.0, 0.0)];
+        assert!(matches!(v.check(&points), Err(InterpolationError::NearlyCoincident { first: 0, second: 1, .. })));
+        let tiny = Rational::new(1, 100_000_000_000);
+        let points = [Point::new(Rational::from(0), Rational::from(0)), Point::new(tiny, Rational::from(1)), Point::new(Rational::from(1), Rational::from(0))];
+        assert_eq!(v.check(&points), Ok(()));
+        // distinct rationals that round to the same f64
+        let near = Rational::new(1, 3) + Rational::new(1, 1 << 62);
+        let points = [Point::new(Rational::new(1, 3), Rational::from(0)), Point::new(near, Rational::from(1))];
+        assert_eq!(v.check(&points), Ok(()));
+    }
+}